@@ -0,0 +1,407 @@
+//! Continued fractions - convergents, best rational approximations and nobility.
+//!
+//! (c) 2025 Anywave Creations
+//! MIT License
+
+use std::fmt;
+
+use crate::tolerance::Tolerance;
+
+/// Maximum number of terms taken from an f64 expansion
+const MAX_F64_TERMS: usize = 64;
+
+/// Minimum run of trailing ones for a ratio to count as noble
+const NOBLE_MIN_ONES: usize = 3;
+
+/// Minimum nobility for a ratio to count as noble
+const NOBLE_THRESHOLD: f64 = 0.5;
+
+/// A non-negative fraction p/q
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fraction {
+    /// Numerator p
+    pub numerator: u64,
+    /// Denominator q (positive for any fraction built by this crate)
+    pub denominator: u64,
+}
+
+impl Fraction {
+    /// Create a new fraction
+    pub const fn new(numerator: u64, denominator: u64) -> Self {
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// The same fraction in lowest terms (0/0 is returned unchanged)
+    pub fn reduced(&self) -> Self {
+        let g = gcd(self.numerator as u128, self.denominator as u128).max(1) as u64;
+        Self::new(self.numerator / g, self.denominator / g)
+    }
+
+    /// Convert to f64
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+impl fmt::Display for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+/// Greatest common divisor by Euclid's algorithm (gcd(0, 0) = 0)
+pub(crate) const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Classification of a frequency ratio by its continued fraction
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RatioClass {
+    /// Close to a fraction with a small denominator
+    SimpleRational(Fraction),
+    /// Expansion ends in a run of ones, like φ; carries the nobility
+    Noble(f64),
+    /// Neither simple nor noble
+    Neither,
+}
+
+/// A simple continued fraction [a0; a1, a2, ...]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ContinuedFraction {
+    terms: Vec<u64>,
+}
+
+impl ContinuedFraction {
+    /// Create from explicit terms
+    pub fn new(terms: Vec<u64>) -> Self {
+        Self { terms }
+    }
+
+    /// Expand the exact ratio p/q.
+    ///
+    /// # Panics
+    /// Panics if q == 0
+    pub fn from_ratio(mut p: u64, mut q: u64) -> Self {
+        if q == 0 {
+            panic!("Denominator must be non-zero");
+        }
+
+        let mut terms = Vec::new();
+        while q != 0 {
+            terms.push(p / q);
+            (p, q) = (q, p % q);
+        }
+
+        Self { terms }
+    }
+
+    /// Expand a real value until its convergent matches to f64 precision.
+    ///
+    /// # Arguments
+    /// * `value` - Value to expand
+    /// * `max_terms` - Maximum number of terms
+    ///
+    /// # Panics
+    /// Panics if value is negative or not finite
+    pub fn from_f64(value: f64, max_terms: usize) -> Self {
+        Self::expand(value, max_terms, |convergent| {
+            (convergent - value).abs() <= 4.0 * f64::EPSILON * value
+        })
+    }
+
+    /// Expand a ratio only until a convergent lies within a tolerance.
+    ///
+    /// Measured ratios carry limited precision, so terms beyond the
+    /// tolerance are noise and would hide the structure of the expansion.
+    ///
+    /// # Panics
+    /// Panics if ratio is not positive and finite
+    pub fn from_ratio_within(ratio: f64, tolerance: Tolerance) -> Self {
+        if ratio <= 0.0 {
+            panic!("Ratio must be positive");
+        }
+        Self::expand(ratio, MAX_F64_TERMS, |convergent| {
+            tolerance.matches(convergent, ratio)
+        })
+    }
+
+    fn expand<F: Fn(f64) -> bool>(value: f64, max_terms: usize, close_enough: F) -> Self {
+        if !value.is_finite() || value < 0.0 {
+            panic!("Value must be finite and non-negative");
+        }
+
+        let mut terms = Vec::new();
+        let mut remainder = value;
+        let (mut p_prev, mut p) = (0.0, 1.0);
+        let (mut q_prev, mut q) = (1.0, 0.0);
+
+        while terms.len() < max_terms {
+            let term = remainder.floor();
+            terms.push(term as u64);
+
+            (p_prev, p) = (p, term * p + p_prev);
+            (q_prev, q) = (q, term * q + q_prev);
+
+            let fraction = remainder - term;
+            if close_enough(p / q) || fraction <= f64::EPSILON {
+                break;
+            }
+            remainder = 1.0 / fraction;
+        }
+
+        Self { terms }
+    }
+
+    /// Terms [a0; a1, a2, ...]
+    pub fn terms(&self) -> &[u64] {
+        &self.terms
+    }
+
+    /// Evaluate the expansion
+    pub fn value(&self) -> f64 {
+        let mut terms = self.terms.iter().rev();
+        let Some(&last) = terms.next() else {
+            return 0.0;
+        };
+        terms.fold(last as f64, |acc, &a| a as f64 + 1.0 / acc)
+    }
+
+    /// Convergents p_k/q_k, stopping early if a term overflows u64
+    pub fn convergents(&self) -> Vec<Fraction> {
+        let mut result = Vec::with_capacity(self.terms.len());
+        let (mut p_prev, mut p) = (0u64, 1u64);
+        let (mut q_prev, mut q) = (1u64, 0u64);
+
+        for &a in &self.terms {
+            let next = a
+                .checked_mul(p)
+                .and_then(|ap| ap.checked_add(p_prev))
+                .zip(a.checked_mul(q).and_then(|aq| aq.checked_add(q_prev)));
+            let Some((p_next, q_next)) = next else {
+                break;
+            };
+
+            (p_prev, p) = (p, p_next);
+            (q_prev, q) = (q, q_next);
+            result.push(Fraction::new(p, q));
+        }
+
+        result
+    }
+
+    /// Semiconvergents (p_(k-1) + m p_k) / (q_(k-1) + m q_k) for 0 < m < a_(k+1).
+    ///
+    /// These are the intermediate fractions between consecutive convergents,
+    /// in order of increasing denominator.
+    pub fn semiconvergents(&self) -> Vec<Fraction> {
+        let convergents = self.convergents();
+        let mut result = Vec::new();
+
+        for (k, &a) in self
+            .terms
+            .iter()
+            .enumerate()
+            .skip(1)
+            .take(convergents.len().saturating_sub(1))
+        {
+            let current = convergents[k - 1];
+            let (p_prev, q_prev) = match k {
+                1 => (1, 0),
+                _ => (convergents[k - 2].numerator, convergents[k - 2].denominator),
+            };
+            for m in 1..a {
+                result.push(Fraction::new(
+                    p_prev + m * current.numerator,
+                    q_prev + m * current.denominator,
+                ));
+            }
+        }
+
+        result
+    }
+
+    /// Length of the trailing run of ones after a0
+    pub fn trailing_ones(&self) -> usize {
+        self.terms
+            .iter()
+            .skip(1)
+            .rev()
+            .take_while(|&&a| a == 1)
+            .count()
+    }
+
+    /// How φ-like the tail of the expansion is.
+    ///
+    /// The fraction of terms after a0 that belong to the trailing run of
+    /// ones: 1.0 for φ itself, 0.0 for expansions that do not end in ones.
+    pub fn nobility(&self) -> f64 {
+        let tail = self.terms.len().saturating_sub(1);
+        if tail == 0 {
+            return 0.0;
+        }
+        self.trailing_ones() as f64 / tail as f64
+    }
+}
+
+impl fmt::Display for ContinuedFraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut terms = self.terms.iter();
+        match terms.next() {
+            Some(a0) => write!(f, "[{}", a0)?,
+            None => return write!(f, "[]"),
+        }
+        for (i, a) in terms.enumerate() {
+            let sep = if i == 0 { "; " } else { ", " };
+            write!(f, "{}{}", sep, a)?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// Find the closest fraction to a value with denominator at most a limit.
+///
+/// # Arguments
+/// * `value` - Non-negative value to approximate
+/// * `max_denominator` - Largest allowed denominator (>= 1)
+///
+/// # Returns
+/// The best rational approximation
+///
+/// # Panics
+/// Panics if max_denominator is 0 or value is negative or not finite
+pub fn best_rational_approximation(value: f64, max_denominator: u64) -> Fraction {
+    if max_denominator == 0 {
+        panic!("max_denominator must be >= 1");
+    }
+
+    let cf = ContinuedFraction::from_f64(value, MAX_F64_TERMS);
+    let convergents = cf.convergents();
+    let mut best = convergents[0];
+    let (mut p_prev, mut q_prev) = (1u64, 0u64);
+
+    for convergent in convergents.into_iter().skip(1) {
+        if convergent.denominator > max_denominator {
+            // Largest semiconvergent that still fits the limit
+            let m = (max_denominator - q_prev) / best.denominator;
+            let semi = Fraction::new(p_prev + m * best.numerator, q_prev + m * best.denominator);
+            if (semi.to_f64() - value).abs() < (best.to_f64() - value).abs() {
+                best = semi;
+            }
+            break;
+        }
+
+        (p_prev, q_prev) = (best.numerator, best.denominator);
+        best = convergent;
+    }
+
+    best
+}
+
+/// Classify a frequency ratio as simple-rational, noble, or neither.
+///
+/// # Arguments
+/// * `ratio` - Positive frequency ratio, e.g. freq2 / freq1
+/// * `max_denominator` - Largest denominator that still counts as simple
+/// * `tolerance` - Tolerance for both tests, typically `Tolerance::Cents`
+///
+/// # Returns
+/// The ratio class
+///
+/// # Panics
+/// Panics if ratio is not positive and finite, or max_denominator is 0
+pub fn classify_ratio(ratio: f64, max_denominator: u64, tolerance: Tolerance) -> RatioClass {
+    if !(ratio.is_finite() && ratio > 0.0) {
+        panic!("Ratio must be positive");
+    }
+    let best = best_rational_approximation(ratio, max_denominator);
+    if best.numerator > 0 && tolerance.matches(best.to_f64(), ratio) {
+        return RatioClass::SimpleRational(best);
+    }
+
+    let cf = ContinuedFraction::from_ratio_within(ratio, tolerance);
+    let nobility = cf.nobility();
+    if cf.trailing_ones() >= NOBLE_MIN_ONES && nobility >= NOBLE_THRESHOLD {
+        RatioClass::Noble(nobility)
+    } else {
+        RatioClass::Neither
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phi::{PHI, PI, SQRT_2};
+
+    #[test]
+    fn test_expansions() {
+        assert_eq!(
+            ContinuedFraction::from_ratio(415, 93).terms(),
+            &[4, 2, 6, 7]
+        );
+        assert_eq!(
+            ContinuedFraction::from_f64(SQRT_2, 6).terms(),
+            &[1, 2, 2, 2, 2, 2]
+        );
+
+        let phi = ContinuedFraction::from_f64(PHI, 64);
+        assert!(phi.terms().iter().all(|&a| a == 1));
+        assert!((phi.value() - PHI).abs() < 1e-15);
+        assert_eq!(phi.nobility(), 1.0);
+        assert_eq!(ContinuedFraction::from_ratio(3, 2).nobility(), 0.0);
+        assert_eq!(
+            ContinuedFraction::from_ratio(415, 93).to_string(),
+            "[4; 2, 6, 7]"
+        );
+    }
+
+    #[test]
+    fn test_convergents_and_semiconvergents() {
+        let cf = ContinuedFraction::from_f64(PI, 4);
+        let convergents: Vec<String> = cf.convergents().iter().map(|c| c.to_string()).collect();
+        assert_eq!(convergents, ["3/1", "22/7", "333/106", "355/113"]);
+
+        let cf = ContinuedFraction::new(vec![0, 3, 2]);
+        let semis: Vec<String> = cf.semiconvergents().iter().map(|c| c.to_string()).collect();
+        assert_eq!(semis, ["1/1", "1/2", "1/4"]);
+    }
+
+    #[test]
+    fn test_best_rational_approximation() {
+        assert_eq!(best_rational_approximation(PI, 10), Fraction::new(22, 7));
+        assert_eq!(
+            best_rational_approximation(PI, 200),
+            Fraction::new(355, 113)
+        );
+        assert_eq!(best_rational_approximation(1.5, 100), Fraction::new(3, 2));
+        assert_eq!(best_rational_approximation(0.26, 3), Fraction::new(1, 3));
+        assert_eq!(best_rational_approximation(0.26, 4), Fraction::new(1, 4));
+    }
+
+    #[test]
+    fn test_classify_ratio() {
+        assert_eq!(
+            classify_ratio(528.0 / 396.0, 16, Tolerance::Cents(1.0)),
+            RatioClass::SimpleRational(Fraction::new(4, 3))
+        );
+        assert!(matches!(
+            classify_ratio(1.6181, 8, Tolerance::Cents(5.0)),
+            RatioClass::Noble(_)
+        ));
+        assert_eq!(
+            classify_ratio(SQRT_2, 8, Tolerance::Cents(1.0)),
+            RatioClass::Neither
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Ratio must be positive")]
+    fn test_classify_rejects_non_positive_ratio() {
+        classify_ratio(0.0, 8, Tolerance::Cents(1.0));
+    }
+}
@@ -0,0 +1,210 @@
+//! Sensory dissonance - Plomp-Levelt roughness curves for sets of partials.
+//!
+//! (c) 2025 Anywave Creations
+//! MIT License
+
+use std::ops::RangeInclusive;
+
+use crate::synth::Partial;
+
+/// Frequency difference (scaled) at which roughness peaks, as fitted by Sethares
+const MAX_ROUGHNESS_POINT: f64 = 0.24;
+/// Critical-band scaling: s = MAX_ROUGHNESS_POINT / (S1 * f_min + S2)
+const S1: f64 = 0.0207;
+const S2: f64 = 18.96;
+/// Exponential decay rates of the Plomp-Levelt curve
+const B1: f64 = 3.51;
+const B2: f64 = 5.75;
+
+/// Calculate the roughness between two sine partials (Sethares' model).
+///
+/// Roughness rises from zero at unison to a peak about a quarter of a
+/// critical band apart, then decays; it scales with the quieter partial.
+///
+/// # Returns
+/// Roughness (0 for identical frequencies or silent partials)
+pub fn pair_roughness(a: &Partial, b: &Partial) -> f64 {
+    let f_min = a.frequency.min(b.frequency);
+    let s = MAX_ROUGHNESS_POINT / (S1 * f_min + S2);
+    let x = s * (b.frequency - a.frequency).abs();
+    a.amplitude.abs().min(b.amplitude.abs()) * ((-B1 * x).exp() - (-B2 * x).exp())
+}
+
+/// Calculate the total sensory roughness of a set of partials.
+///
+/// # Returns
+/// Sum of pair_roughness over every pair of partials
+pub fn roughness(partials: &[Partial]) -> f64 {
+    partials
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| partials[i + 1..].iter().map(move |b| pair_roughness(a, b)))
+        .sum()
+}
+
+/// One point on a dissonance curve
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DissonancePoint {
+    /// Frequency ratio of the second tone to the first
+    pub ratio: f64,
+    /// Total roughness of both tones sounding together
+    pub dissonance: f64,
+}
+
+/// Sweep a copy of a timbre against itself and record the roughness.
+///
+/// The second tone is the same partials scaled by each ratio; ratios are
+/// spaced evenly in cents across the range.
+///
+/// # Arguments
+/// * `partials` - Timbre of both tones
+/// * `range` - Ratios to sweep (must be positive and ordered)
+/// * `steps` - Number of points, including both ends (must be >= 2)
+///
+/// # Returns
+/// Dissonance at each ratio, in ascending order
+///
+/// # Panics
+/// Panics if the range is not positive and ordered, or steps < 2
+pub fn dissonance_curve(
+    partials: &[Partial],
+    range: RangeInclusive<f64>,
+    steps: usize,
+) -> Vec<DissonancePoint> {
+    let (low, high) = (*range.start(), *range.end());
+    if !(low > 0.0 && high.is_finite() && low <= high) {
+        panic!("Ratio range must be positive and ordered");
+    }
+    if steps < 2 {
+        panic!("Steps must be >= 2");
+    }
+
+    let span = (high / low).log2();
+    (0..steps)
+        .map(|step| {
+            let ratio = low * (span * step as f64 / (steps - 1) as f64).exp2();
+            let mut combined = partials.to_vec();
+            combined.extend(partials.iter().map(|p| Partial {
+                frequency: p.frequency * ratio,
+                ..*p
+            }));
+            DissonancePoint {
+                ratio,
+                dissonance: roughness(&combined),
+            }
+        })
+        .collect()
+}
+
+/// Find the local minima of a dissonance curve.
+///
+/// Interior points lower than both neighbours are refined with parabolic
+/// interpolation in log-ratio; the curve's end points are never minima.
+///
+/// # Returns
+/// Minima in ascending ratio order
+pub fn local_minima(curve: &[DissonancePoint]) -> Vec<DissonancePoint> {
+    curve
+        .windows(3)
+        .filter(|w| w[1].dissonance < w[0].dissonance && w[1].dissonance <= w[2].dissonance)
+        .map(|w| {
+            let (y0, y1, y2) = (w[0].dissonance, w[1].dissonance, w[2].dissonance);
+            let denominator = y0 - 2.0 * y1 + y2;
+            if denominator <= 0.0 {
+                return w[1];
+            }
+            let offset = 0.5 * (y0 - y2) / denominator;
+            let (left, right) = (
+                (w[1].ratio / w[0].ratio).log2(),
+                (w[2].ratio / w[1].ratio).log2(),
+            );
+            let step = if offset < 0.0 { left } else { right };
+            DissonancePoint {
+                ratio: w[1].ratio * (offset * step).exp2(),
+                dissonance: y1 - 0.25 * (y0 - y2) * offset,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frequencies::{MaterialFrequency, A432};
+
+    #[test]
+    fn test_pair_roughness() {
+        let a = Partial::new(A432, 1.0);
+        assert_eq!(pair_roughness(&a, &a), 0.0);
+
+        // Roughness peaks within a critical band and fades beyond it
+        let near = pair_roughness(&a, &Partial::new(A432 + 20.0, 1.0));
+        let far = pair_roughness(&a, &Partial::new(A432 * 2.0, 1.0));
+        assert!(near > 0.1);
+        assert!(far < 1e-3);
+
+        // Scales with the quieter partial and is symmetric
+        let quiet = Partial::new(A432 + 20.0, 0.5);
+        assert!((pair_roughness(&a, &quiet) - 0.5 * near).abs() < 1e-12);
+        assert_eq!(pair_roughness(&a, &quiet), pair_roughness(&quiet, &a));
+    }
+
+    #[test]
+    fn test_roughness() {
+        assert_eq!(roughness(&[]), 0.0);
+        assert_eq!(roughness(&[Partial::new(A432, 1.0)]), 0.0);
+
+        let harmonic = Partial::harmonic_series(A432, 6);
+        let pairs: f64 = (0..6)
+            .flat_map(|i| (i + 1..6).map(move |j| (i, j)))
+            .map(|(i, j)| pair_roughness(&harmonic[i], &harmonic[j]))
+            .sum();
+        assert!((roughness(&harmonic) - pairs).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_harmonic_curve_minima() {
+        let timbre = Partial::harmonic_series(261.63, 6);
+        let curve = dissonance_curve(&timbre, 1.0..=2.1, 2000);
+        assert_eq!(curve.len(), 2000);
+        assert_eq!(curve[0].ratio, 1.0);
+        assert!((curve[1999].ratio - 2.1).abs() < 1e-12);
+
+        let minima = local_minima(&curve);
+        for just in [5.0 / 4.0, 4.0 / 3.0, 3.0 / 2.0, 5.0 / 3.0, 2.0] {
+            assert!(
+                minima
+                    .iter()
+                    .any(|m| (m.ratio / just).log2().abs() < 2.0 / 1200.0),
+                "no minimum near {}",
+                just
+            );
+        }
+
+        // The fifth is smoother than the tritone next to it
+        let at = |r: f64| dissonance_curve(&timbre, r..=r, 2)[0].dissonance;
+        assert!(at(1.5) < at(2.0_f64.sqrt()));
+    }
+
+    #[test]
+    fn test_material_timbre() {
+        // An inharmonic material timbre: partials at 1, 2.76 and 5.40 times the base
+        let base = MaterialFrequency::Copper.frequency() / 64.0;
+        let timbre: Vec<Partial> = [(1.0, 1.0), (2.76, 0.6), (5.40, 0.4)]
+            .into_iter()
+            .map(|(n, amplitude)| Partial::new(base * n, amplitude))
+            .collect();
+        let curve = dissonance_curve(&timbre, 1.0..=3.0, 1500);
+        let minima = local_minima(&curve);
+        assert!(minima
+            .iter()
+            .any(|m| (m.ratio / 2.76).log2().abs() < 2.0 / 1200.0));
+        assert!(local_minima(&curve[..2]).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Steps must be >= 2")]
+    fn test_curve_panics() {
+        dissonance_curve(&[Partial::new(A432, 1.0)], 1.0..=2.0, 1);
+    }
+}
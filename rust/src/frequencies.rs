@@ -3,6 +3,10 @@
 //! (c) 2025 Anywave Creations
 //! MIT License
 
+pub mod interval;
+
+use crate::tolerance::Tolerance;
+
 /// Schumann resonance fundamental frequency (Hz)
 pub const SCHUMANN_FUNDAMENTAL: f64 = 7.83;
 
@@ -33,6 +37,15 @@ pub const A432: f64 = 432.0;
 /// Concert pitch A at 440 Hz (ISO 16 standard)
 pub const A440: f64 = 440.0;
 
+/// MIDI note number of A4, the reference pitch note
+pub const MIDI_A4: u8 = 69;
+
+/// Centre (no bend) value of a 14-bit MIDI pitch bend
+pub const PITCH_BEND_CENTER: u16 = 8192;
+
+/// Maximum value of a 14-bit MIDI pitch bend
+pub const PITCH_BEND_MAX: u16 = 16383;
+
 /// Ut (Do) - Liberation from fear and guilt
 pub const SOLFEGGIO_UT: f64 = 396.0;
 
@@ -97,6 +110,32 @@ pub enum MaterialFrequency {
 }
 
 impl MaterialFrequency {
+    /// All built-in materials, from highest to lowest frequency
+    pub const ALL: [Self; 8] = [
+        Self::Quartz,
+        Self::Gold,
+        Self::Silver,
+        Self::Copper,
+        Self::Iron,
+        Self::Obsidian,
+        Self::Granite,
+        Self::Limestone,
+    ];
+
+    /// Get the human-readable name
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Quartz => "Quartz",
+            Self::Gold => "Gold",
+            Self::Silver => "Silver",
+            Self::Copper => "Copper",
+            Self::Iron => "Iron",
+            Self::Obsidian => "Obsidian",
+            Self::Granite => "Granite",
+            Self::Limestone => "Limestone",
+        }
+    }
+
     /// Get the properties for this material
     pub const fn properties(&self) -> MaterialProperties {
         match self {
@@ -169,6 +208,318 @@ pub fn cents_difference(freq1: f64, freq2: f64) -> f64 {
     1200.0 * (freq2 / freq1).log2()
 }
 
+/// A frequency expressed as the nearest MIDI note plus a cents offset
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MidiPitch {
+    /// MIDI note number (0-127)
+    pub note: u8,
+    /// Offset from the note in cents (-50 to +50)
+    pub cents: f64,
+}
+
+impl MidiPitch {
+    /// Get the 14-bit pitch-bend value that shifts the note by the cents offset
+    ///
+    /// # Arguments
+    /// * `bend_range` - Synth pitch-bend range in semitones (e.g. 2.0)
+    ///
+    /// # Panics
+    /// Panics if bend_range is not positive (including NaN)
+    pub fn pitch_bend(&self, bend_range: f64) -> u16 {
+        pitch_bend_value(self.cents, bend_range)
+    }
+}
+
+/// Calculate the frequency of a MIDI note.
+///
+/// # Arguments
+/// * `note` - MIDI note number (69 = A4)
+/// * `reference` - Frequency of A4 in Hz (e.g. `A432` or `A440`)
+///
+/// # Returns
+/// Frequency in Hz
+pub fn midi_to_frequency(note: u8, reference: f64) -> f64 {
+    reference * 2.0_f64.powf((note as f64 - MIDI_A4 as f64) / 12.0)
+}
+
+/// Find the nearest MIDI note to a frequency.
+///
+/// # Arguments
+/// * `frequency` - Frequency in Hz
+/// * `reference` - Frequency of A4 in Hz (e.g. `A432` or `A440`)
+///
+/// # Returns
+/// The nearest note and cents offset, or None if it falls outside 0-127
+pub fn frequency_to_midi(frequency: f64, reference: f64) -> Option<MidiPitch> {
+    if frequency <= 0.0 || reference <= 0.0 {
+        return None;
+    }
+
+    let exact = MIDI_A4 as f64 + 12.0 * (frequency / reference).log2();
+    let nearest = exact.round();
+    if !(0.0..=127.0).contains(&nearest) {
+        return None;
+    }
+
+    let note = nearest as u8;
+    Some(MidiPitch {
+        note,
+        cents: cents_difference(midi_to_frequency(note, reference), frequency),
+    })
+}
+
+/// Calculate the 14-bit MIDI pitch-bend value for a cents offset.
+///
+/// # Arguments
+/// * `cents` - Desired bend in cents (positive = up)
+/// * `bend_range` - Synth pitch-bend range in semitones (e.g. 2.0)
+///
+/// # Returns
+/// Pitch-bend value from 0 to 16383, clamped to the bend range (8192 = no bend)
+///
+/// # Panics
+/// Panics if bend_range is not positive (including NaN)
+pub fn pitch_bend_value(cents: f64, bend_range: f64) -> u16 {
+    if bend_range.is_nan() || bend_range <= 0.0 {
+        panic!("Bend range must be positive");
+    }
+
+    let offset = cents / (bend_range * 100.0) * PITCH_BEND_CENTER as f64;
+    (PITCH_BEND_CENTER as f64 + offset)
+        .round()
+        .clamp(0.0, PITCH_BEND_MAX as f64) as u16
+}
+
+/// Check if two frequencies match.
+///
+/// # Arguments
+/// * `freq1` - Measured frequency in Hz
+/// * `freq2` - Expected frequency in Hz
+/// * `tolerance` - Acceptable deviation (a bare f64 is absolute, in Hz)
+///
+/// # Returns
+/// True if freq1 is within tolerance of freq2
+pub fn frequencies_match(freq1: f64, freq2: f64, tolerance: impl Into<Tolerance>) -> bool {
+    tolerance.into().matches(freq1, freq2)
+}
+
+/// Find which harmonic of a fundamental a frequency matches.
+///
+/// # Arguments
+/// * `frequency` - Measured frequency in Hz
+/// * `fundamental` - Fundamental frequency in Hz
+/// * `tolerance` - Acceptable deviation from the nearest harmonic
+///
+/// # Returns
+/// The harmonic number (1 = fundamental), or None if no harmonic is within tolerance
+pub fn matching_harmonic(
+    frequency: f64,
+    fundamental: f64,
+    tolerance: impl Into<Tolerance>,
+) -> Option<u32> {
+    if frequency <= 0.0 || fundamental <= 0.0 {
+        return None;
+    }
+
+    let nearest = (frequency / fundamental).round().max(1.0) as u32;
+    tolerance
+        .into()
+        .matches(frequency, harmonic_of(fundamental, nearest))
+        .then_some(nearest)
+}
+
+/// Find by how many octaves a frequency is shifted from a reference.
+///
+/// # Arguments
+/// * `frequency` - Measured frequency in Hz
+/// * `reference` - Reference frequency in Hz
+/// * `tolerance` - Acceptable deviation from the nearest octave
+///
+/// # Returns
+/// The octave shift (positive = up), or None if no octave is within tolerance
+pub fn matching_octave(
+    frequency: f64,
+    reference: f64,
+    tolerance: impl Into<Tolerance>,
+) -> Option<i32> {
+    if frequency <= 0.0 || reference <= 0.0 {
+        return None;
+    }
+
+    let nearest = (frequency / reference).log2().round() as i32;
+    tolerance
+        .into()
+        .matches(frequency, octave_of(reference, nearest))
+        .then_some(nearest)
+}
+
+/// Power and phase of one target frequency in a sample buffer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TonePower {
+    /// Target frequency in Hz
+    pub frequency: f64,
+    /// Mean-square amplitude of the component (A²/2 for a sine of amplitude A)
+    pub power: f64,
+    /// Cosine phase at the first sample in radians (-π to π)
+    pub phase: f64,
+}
+
+impl TonePower {
+    /// Peak amplitude of the component
+    pub fn amplitude(&self) -> f64 {
+        (2.0 * self.power).sqrt()
+    }
+}
+
+/// Goertzel filter state for one target frequency
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct GoertzelBin {
+    frequency: f64,
+    omega: f64,
+    coefficient: f64,
+    s1: f64,
+    s2: f64,
+}
+
+/// Streaming Goertzel detector for a fixed set of target frequencies.
+///
+/// Feed samples with [`process`](Self::process) in blocks of any size, then
+/// read the result with [`results`](Self::results) or [`finish`](Self::finish).
+/// Each target costs one multiply-add per sample, far less than an FFT
+/// when only a handful of frequencies matter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoertzelDetector {
+    sample_rate: f64,
+    bins: Vec<GoertzelBin>,
+    count: usize,
+}
+
+impl GoertzelDetector {
+    /// Create a detector.
+    ///
+    /// # Arguments
+    /// * `sample_rate` - Samples per second
+    /// * `targets` - Frequencies to measure in Hz (e.g. `SCHUMANN_HARMONICS`);
+    ///   targets above the Nyquist frequency alias
+    ///
+    /// # Panics
+    /// Panics if sample_rate <= 0
+    pub fn new(sample_rate: f64, targets: &[f64]) -> Self {
+        if sample_rate.is_nan() || sample_rate <= 0.0 {
+            panic!("Sample rate must be positive");
+        }
+
+        let bins = targets
+            .iter()
+            .map(|&frequency| {
+                let omega = std::f64::consts::TAU * frequency / sample_rate;
+                GoertzelBin {
+                    frequency,
+                    omega,
+                    coefficient: 2.0 * omega.cos(),
+                    s1: 0.0,
+                    s2: 0.0,
+                }
+            })
+            .collect();
+
+        Self {
+            sample_rate,
+            bins,
+            count: 0,
+        }
+    }
+
+    /// Samples per second
+    pub fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
+
+    /// Number of samples processed since the last reset
+    pub fn sample_count(&self) -> usize {
+        self.count
+    }
+
+    /// Feed a block of samples
+    pub fn process(&mut self, samples: &[f32]) {
+        for bin in &mut self.bins {
+            let (mut s1, mut s2) = (bin.s1, bin.s2);
+            for &x in samples {
+                (s1, s2) = (x as f64 + bin.coefficient * s1 - s2, s1);
+            }
+            (bin.s1, bin.s2) = (s1, s2);
+        }
+        self.count += samples.len();
+    }
+
+    /// Power and phase of each target over all samples since the last reset.
+    ///
+    /// # Returns
+    /// One entry per target, in order; all zero if no samples were processed
+    pub fn results(&self) -> Vec<TonePower> {
+        let n = self.count as f64;
+        self.bins
+            .iter()
+            .map(|bin| {
+                if self.count == 0 {
+                    return TonePower {
+                        frequency: bin.frequency,
+                        power: 0.0,
+                        phase: 0.0,
+                    };
+                }
+
+                // y = s1 - e^(-iω) s2 is the DTFT rotated by e^(iω(N-1))
+                let re = bin.s1 - bin.omega.cos() * bin.s2;
+                let im = bin.omega.sin() * bin.s2;
+                let rotation = -bin.omega * (n - 1.0);
+                let (sin, cos) = rotation.sin_cos();
+                let (re, im) = (re * cos - im * sin, re * sin + im * cos);
+
+                TonePower {
+                    frequency: bin.frequency,
+                    power: 2.0 * (re * re + im * im) / (n * n),
+                    phase: im.atan2(re),
+                }
+            })
+            .collect()
+    }
+
+    /// Return the results and reset for the next block
+    pub fn finish(&mut self) -> Vec<TonePower> {
+        let results = self.results();
+        self.reset();
+        results
+    }
+
+    /// Clear the filter state
+    pub fn reset(&mut self) {
+        for bin in &mut self.bins {
+            bin.s1 = 0.0;
+            bin.s2 = 0.0;
+        }
+        self.count = 0;
+    }
+}
+
+/// Measure power and phase at target frequencies with the Goertzel algorithm.
+///
+/// # Arguments
+/// * `samples` - Mono sample buffer
+/// * `sample_rate` - Samples per second
+/// * `targets` - Frequencies to measure in Hz
+///
+/// # Returns
+/// One entry per target, in order
+///
+/// # Panics
+/// Panics if sample_rate <= 0
+pub fn goertzel(samples: &[f32], sample_rate: f64, targets: &[f64]) -> Vec<TonePower> {
+    let mut detector = GoertzelDetector::new(sample_rate, targets);
+    detector.process(samples);
+    detector.results()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,6 +534,7 @@ mod tests {
     fn test_material_frequency() {
         assert_eq!(MaterialFrequency::Quartz.frequency(), 32768.0);
         assert_eq!(MaterialFrequency::Gold.alpha_affinity(), 0.95);
+        assert_eq!(MaterialFrequency::ALL[3].name(), "Copper");
     }
 
     #[test]
@@ -202,4 +554,116 @@ mod tests {
         // Octave = 1200 cents
         assert!((cents_difference(440.0, 880.0) - 1200.0).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_frequencies_match() {
+        assert!(frequencies_match(7.85, SCHUMANN_FUNDAMENTAL, 0.05));
+        assert!(frequencies_match(A432, A440, Tolerance::Cents(32.0)));
+        assert!(!frequencies_match(A432, A440, Tolerance::Relative(0.01)));
+    }
+
+    #[test]
+    fn test_matching_harmonic_and_octave() {
+        assert_eq!(matching_harmonic(23.5, SCHUMANN_FUNDAMENTAL, 0.1), Some(3));
+        assert_eq!(matching_harmonic(27.3, SCHUMANN_FUNDAMENTAL, 0.1), None);
+        assert_eq!(
+            matching_octave(A432 / 4.0, A432, Tolerance::Cents(1.0)),
+            Some(-2)
+        );
+        assert_eq!(
+            matching_octave(SOLFEGGIO_MI, 264.0, Tolerance::Ulps(0)),
+            Some(1)
+        );
+        assert_eq!(matching_octave(A440, A432, Tolerance::Cents(5.0)), None);
+    }
+
+    #[test]
+    fn test_midi_conversion() {
+        assert_eq!(midi_to_frequency(69, A440), 440.0);
+        assert!((midi_to_frequency(60, A440) - 261.6255653005986).abs() < 1e-9);
+        assert!((midi_to_frequency(81, A432) - 864.0).abs() < 1e-9);
+
+        let mi = frequency_to_midi(SOLFEGGIO_MI, A440).unwrap();
+        assert_eq!(mi.note, 72);
+        assert!((mi.cents - 15.641).abs() < 1e-3);
+        assert_eq!(frequency_to_midi(A432, A432).unwrap().cents, 0.0);
+        assert!(frequency_to_midi(SCHUMANN_FUNDAMENTAL, A440).is_none());
+        assert_eq!(
+            frequency_to_midi(octave_of(SCHUMANN_FUNDAMENTAL, 5), A432)
+                .unwrap()
+                .note,
+            60
+        );
+    }
+
+    #[test]
+    fn test_pitch_bend() {
+        assert_eq!(pitch_bend_value(0.0, 2.0), PITCH_BEND_CENTER);
+        assert_eq!(pitch_bend_value(200.0, 2.0), PITCH_BEND_MAX);
+        assert_eq!(pitch_bend_value(-200.0, 2.0), 0);
+        assert_eq!(pitch_bend_value(100.0, 2.0), 12288);
+        assert_eq!(pitch_bend_value(1000.0, 2.0), PITCH_BEND_MAX);
+
+        let pitch = frequency_to_midi(SOLFEGGIO_UT, A440).unwrap();
+        assert_eq!(pitch.pitch_bend(12.0), pitch_bend_value(pitch.cents, 12.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Bend range must be positive")]
+    fn test_pitch_bend_rejects_nan_range() {
+        frequency_to_midi(A440, A440).unwrap().pitch_bend(f64::NAN);
+    }
+
+    fn cosine(frequency: f64, amplitude: f64, phase: f64, rate: f64, count: usize) -> Vec<f32> {
+        (0..count)
+            .map(|i| {
+                let t = i as f64 / rate;
+                (amplitude * (std::f64::consts::TAU * frequency * t + phase).cos()) as f32
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_goertzel() {
+        let rate = 1000.0;
+        let mut samples = cosine(SCHUMANN_2ND, 0.5, 0.3, rate, 10_000);
+        let material = octave_of(MaterialFrequency::Copper.frequency(), -7);
+        for (s, m) in samples
+            .iter_mut()
+            .zip(cosine(material, 0.25, -1.0, rate, 10_000))
+        {
+            *s += m;
+        }
+
+        let results = goertzel(&samples, rate, &[SCHUMANN_HARMONICS[1], material, 50.0]);
+        assert_eq!(results[0].frequency, SCHUMANN_2ND);
+        assert!((results[0].amplitude() - 0.5).abs() < 1e-3);
+        assert!((results[0].phase - 0.3).abs() < 1e-2);
+        assert!((results[1].power - 0.25 * 0.25 / 2.0).abs() < 1e-3);
+        assert!((results[1].phase + 1.0).abs() < 1e-2);
+        assert!(results[2].power < 1e-5);
+        assert!(goertzel(&[], rate, &[10.0])[0].power == 0.0);
+    }
+
+    #[test]
+    fn test_goertzel_streaming() {
+        let rate = 8000.0;
+        let samples = cosine(SOLFEGGIO_MI, 0.8, 0.0, rate, 4000);
+        let mut detector = GoertzelDetector::new(rate, &SOLFEGGIO_FREQUENCIES);
+        for block in samples.chunks(256) {
+            detector.process(block);
+        }
+        assert_eq!(detector.sample_count(), 4000);
+
+        let streamed = detector.finish();
+        assert_eq!(streamed, goertzel(&samples, rate, &SOLFEGGIO_FREQUENCIES));
+        assert_eq!(detector.sample_count(), 0);
+
+        let strongest = streamed
+            .iter()
+            .max_by(|a, b| a.power.total_cmp(&b.power))
+            .unwrap();
+        assert_eq!(strongest.frequency, SOLFEGGIO_MI);
+        assert!((strongest.amplitude() - 0.8).abs() < 1e-3);
+    }
 }
@@ -0,0 +1,616 @@
+//! Musical intervals - naming frequency ratios and measuring their consonance.
+//!
+//! (c) 2025 Anywave Creations
+//! MIT License
+
+use std::fmt;
+
+use super::cents_difference;
+use crate::continued_fraction::{gcd, Fraction};
+
+/// Largest ratio (and reciprocal) accepted by `harmonic_entropy`: four octaves
+pub const MAX_ENTROPY_RATIO: f64 = 16.0;
+
+/// Largest perceptual spread accepted by `harmonic_entropy`, in cents
+pub const MAX_ENTROPY_SPREAD: f64 = 200.0;
+
+/// Largest denominator accepted by `harmonic_entropy`
+pub const MAX_ENTROPY_DENOMINATOR: u64 = 10_000;
+
+/// Simple interval within one octave, tuned to its 5-limit just ratio
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interval {
+    Unison,
+    MinorSecond,
+    MajorSecond,
+    MinorThird,
+    MajorThird,
+    PerfectFourth,
+    Tritone,
+    PerfectFifth,
+    MinorSixth,
+    MajorSixth,
+    MinorSeventh,
+    MajorSeventh,
+    Octave,
+}
+
+impl Interval {
+    /// All simple intervals, from unison to octave
+    pub const ALL: [Self; 13] = [
+        Self::Unison,
+        Self::MinorSecond,
+        Self::MajorSecond,
+        Self::MinorThird,
+        Self::MajorThird,
+        Self::PerfectFourth,
+        Self::Tritone,
+        Self::PerfectFifth,
+        Self::MinorSixth,
+        Self::MajorSixth,
+        Self::MinorSeventh,
+        Self::MajorSeventh,
+        Self::Octave,
+    ];
+
+    /// Size in 12-TET semitones
+    pub const fn semitones(&self) -> u32 {
+        match self {
+            Self::Unison => 0,
+            Self::MinorSecond => 1,
+            Self::MajorSecond => 2,
+            Self::MinorThird => 3,
+            Self::MajorThird => 4,
+            Self::PerfectFourth => 5,
+            Self::Tritone => 6,
+            Self::PerfectFifth => 7,
+            Self::MinorSixth => 8,
+            Self::MajorSixth => 9,
+            Self::MinorSeventh => 10,
+            Self::MajorSeventh => 11,
+            Self::Octave => 12,
+        }
+    }
+
+    /// Just intonation ratio
+    pub const fn ratio(&self) -> Fraction {
+        let (p, q) = match self {
+            Self::Unison => (1, 1),
+            Self::MinorSecond => (16, 15),
+            Self::MajorSecond => (9, 8),
+            Self::MinorThird => (6, 5),
+            Self::MajorThird => (5, 4),
+            Self::PerfectFourth => (4, 3),
+            Self::Tritone => (45, 32),
+            Self::PerfectFifth => (3, 2),
+            Self::MinorSixth => (8, 5),
+            Self::MajorSixth => (5, 3),
+            Self::MinorSeventh => (9, 5),
+            Self::MajorSeventh => (15, 8),
+            Self::Octave => (2, 1),
+        };
+        Fraction::new(p, q)
+    }
+
+    /// Size of the just ratio in cents
+    pub fn cents(&self) -> f64 {
+        1200.0 * self.ratio().to_f64().log2()
+    }
+
+    /// Get the human-readable name
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Unison => "unison",
+            Self::MinorSecond => "minor second",
+            Self::MajorSecond => "major second",
+            Self::MinorThird => "minor third",
+            Self::MajorThird => "major third",
+            Self::PerfectFourth => "perfect fourth",
+            Self::Tritone => "tritone",
+            Self::PerfectFifth => "perfect fifth",
+            Self::MinorSixth => "minor sixth",
+            Self::MajorSixth => "major sixth",
+            Self::MinorSeventh => "minor seventh",
+            Self::MajorSeventh => "major seventh",
+            Self::Octave => "octave",
+        }
+    }
+
+    /// Name of this interval widened by one octave (e.g. "major tenth")
+    const fn compound_name(&self) -> Option<&'static str> {
+        match self {
+            Self::MinorSecond => Some("minor ninth"),
+            Self::MajorSecond => Some("major ninth"),
+            Self::MinorThird => Some("minor tenth"),
+            Self::MajorThird => Some("major tenth"),
+            Self::PerfectFourth => Some("perfect eleventh"),
+            Self::Tritone => Some("augmented eleventh"),
+            Self::PerfectFifth => Some("perfect twelfth"),
+            Self::MinorSixth => Some("minor thirteenth"),
+            Self::MajorSixth => Some("major thirteenth"),
+            Self::MinorSeventh => Some("minor fourteenth"),
+            Self::MajorSeventh => Some("major fourteenth"),
+            Self::Octave => Some("double octave"),
+            Self::Unison => None,
+        }
+    }
+}
+
+/// The interval closest to a measured ratio
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntervalMatch {
+    /// Simple interval
+    pub interval: Interval,
+    /// Extra octaves for compound intervals (a major tenth is a major third + 1)
+    pub octaves: u32,
+    /// Measured minus just size in cents
+    pub cents: f64,
+    /// True if the second frequency is lower than the first
+    pub descending: bool,
+}
+
+impl IntervalMatch {
+    /// Just ratio of the full (possibly compound) interval, in lowest terms
+    ///
+    /// # Returns
+    /// The ratio, or None if it does not fit in a u64 fraction
+    pub fn ratio(&self) -> Option<Fraction> {
+        let simple = self.interval.ratio();
+        let numerator = 2_u64
+            .checked_pow(self.octaves)?
+            .checked_mul(simple.numerator)?;
+        Some(Fraction::new(numerator, simple.denominator).reduced())
+    }
+}
+
+impl fmt::Display for IntervalMatch {
+    /// Formats as e.g. "major tenth +3.9c" or "perfect fifth + 2 octaves -0.1c"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.octaves, self.interval.compound_name()) {
+            (0, _) => write!(f, "{}", self.interval.name())?,
+            (1, Some(name)) => write!(f, "{}", name)?,
+            (1, None) => write!(f, "{} + 1 octave", self.interval.name())?,
+            (n, _) => write!(f, "{} + {} octaves", self.interval.name(), n)?,
+        }
+        if self.descending {
+            write!(f, " down")?;
+        }
+        write!(f, " {:+.1}c", self.cents)
+    }
+}
+
+/// Name the interval closest to a frequency ratio.
+///
+/// # Arguments
+/// * `ratio` - Frequency ratio (values below 1 are descending intervals)
+///
+/// # Returns
+/// The nearest just interval with its cents error, or None if the ratio is not positive
+pub fn identify_ratio(ratio: f64) -> Option<IntervalMatch> {
+    if !(ratio.is_finite() && ratio > 0.0) {
+        return None;
+    }
+
+    let cents = 1200.0 * ratio.log2();
+    let size = cents.abs();
+    let mut octaves = (size / 1200.0).floor() as u32;
+    let remainder = size - 1200.0 * octaves as f64;
+
+    let mut interval = Interval::ALL
+        .into_iter()
+        .min_by(|a, b| {
+            (remainder - a.cents())
+                .abs()
+                .total_cmp(&(remainder - b.cents()).abs())
+        })
+        .unwrap_or(Interval::Unison);
+
+    // A near-unison above one octave is better named as a (multiple) octave
+    if interval == Interval::Unison && octaves > 0 {
+        interval = Interval::Octave;
+        octaves -= 1;
+    }
+
+    Some(IntervalMatch {
+        interval,
+        octaves,
+        cents: size - 1200.0 * octaves as f64 - interval.cents(),
+        descending: cents < 0.0,
+    })
+}
+
+/// Name the interval between two frequencies.
+///
+/// # Returns
+/// The interval from freq1 to freq2, or None if either frequency is not positive
+pub fn identify_interval(freq1: f64, freq2: f64) -> Option<IntervalMatch> {
+    if !(freq1 > 0.0 && freq2 > 0.0) {
+        return None;
+    }
+    let mut matched = identify_ratio(freq2 / freq1)?;
+    // Measure the error with cents_difference on the ascending pair
+    let (low, high) = if freq1 <= freq2 {
+        (freq1, freq2)
+    } else {
+        (freq2, freq1)
+    };
+    let just = matched.interval.ratio().to_f64() * 2.0_f64.powi(matched.octaves as i32);
+    matched.cents = cents_difference(low * just, high);
+    Some(matched)
+}
+
+/// Reduce a fraction to lowest terms
+///
+/// # Panics
+/// Panics if the numerator or denominator is 0
+fn reduced(ratio: Fraction) -> (u64, u64) {
+    if ratio.numerator == 0 || ratio.denominator == 0 {
+        panic!("Ratio terms must be positive");
+    }
+    let ratio = ratio.reduced();
+    (ratio.numerator, ratio.denominator)
+}
+
+/// Calculate the Tenney height log₂(p·q) of a ratio in lowest terms.
+///
+/// Lower is more consonant: 1/1 = 0, 2/1 = 1, 3/2 ≈ 2.585.
+///
+/// # Panics
+/// Panics if the numerator or denominator is 0
+pub fn tenney_height(ratio: Fraction) -> f64 {
+    let (p, q) = reduced(ratio);
+    (p as f64).log2() + (q as f64).log2()
+}
+
+/// Calculate Euler's gradus suavitatis of a ratio.
+///
+/// For p/q in lowest terms with p·q = ∏ pᵢ^eᵢ, the gradus is 1 + Σ eᵢ(pᵢ - 1).
+/// Lower is more consonant: 1/1 = 1, 2/1 = 2, 3/2 = 4.
+///
+/// Factoring uses trial division by small primes, then Miller-Rabin and
+/// Pollard's rho, so even 64-bit semiprimes take only milliseconds.
+///
+/// # Returns
+/// The gradus, saturating at u64::MAX for ratios of two huge primes
+///
+/// # Panics
+/// Panics if the numerator or denominator is 0
+pub fn euler_gradus(ratio: Fraction) -> u64 {
+    let (p, q) = reduced(ratio);
+    prime_sum(p).saturating_add(prime_sum(q)).saturating_add(1)
+}
+
+/// Largest factor tried by trial division before switching to Pollard's rho
+const TRIAL_DIVISION_LIMIT: u64 = 1_000;
+
+/// Sum of (prime - 1) over the prime factors of n, with multiplicity
+fn prime_sum(mut n: u64) -> u64 {
+    let mut sum = 0;
+    let mut factor = 2;
+    while factor <= TRIAL_DIVISION_LIMIT && factor <= n / factor {
+        while n.is_multiple_of(factor) {
+            sum += factor - 1;
+            n /= factor;
+        }
+        factor += 1;
+    }
+    sum + large_prime_sum(n)
+}
+
+/// prime_sum for n with no prime factors up to TRIAL_DIVISION_LIMIT
+fn large_prime_sum(n: u64) -> u64 {
+    if n == 1 {
+        0
+    } else if is_prime(n) {
+        n - 1
+    } else {
+        let divisor = pollard_rho(n);
+        large_prime_sum(divisor) + large_prime_sum(n / divisor)
+    }
+}
+
+fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1;
+    base %= modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Deterministic Miller-Rabin test; these bases cover every u64
+fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    for base in BASES {
+        if n.is_multiple_of(base) {
+            return n == base;
+        }
+    }
+    let shift = (n - 1).trailing_zeros();
+    let odd = (n - 1) >> shift;
+    'bases: for base in BASES {
+        let mut x = pow_mod(base, odd, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..shift {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Find a nontrivial divisor of an odd composite n (Pollard's rho, Floyd cycle)
+fn pollard_rho(n: u64) -> u64 {
+    for increment in 1.. {
+        let step = |x: u64| ((x as u128 * x as u128 + increment as u128) % n as u128) as u64;
+        let (mut slow, mut fast, mut divisor) = (2, 2, 1);
+        while divisor == 1 {
+            slow = step(slow);
+            fast = step(step(fast));
+            divisor = gcd(slow.abs_diff(fast) as u128, n as u128) as u64;
+        }
+        if divisor != n {
+            return divisor;
+        }
+    }
+    unreachable!("rho eventually splits every composite")
+}
+
+/// Abramowitz-Stegun 7.1.26 approximation of erf (error < 1.5e-7)
+fn erf(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.3275911 * x.abs());
+    let poly = t
+        * (0.254829592
+            + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    let y = 1.0 - poly * (-x * x).exp();
+    if x < 0.0 {
+        -y
+    } else {
+        y
+    }
+}
+
+/// Calculate the harmonic entropy of a ratio in bits.
+///
+/// The ratio is heard as a Gaussian blur in pitch; each fraction with
+/// denominator up to `max_denominator` claims the stretch of pitch between
+/// its mediants with its neighbours, and the entropy of the resulting
+/// probabilities measures how ambiguous the interval sounds. Simple ratios
+/// have wide domains and low entropy.
+///
+/// The fractions are walked in order one at a time, so memory use is
+/// constant. Time grows with the number of fractions within six spreads of
+/// the ratio, roughly 0.3 · max_denominator² · (width of that window): a few
+/// thousand with the defaults, and up to about 7·10⁸ at every limit at once.
+///
+/// # Arguments
+/// * `ratio` - Frequency ratio (within 1/MAX_ENTROPY_RATIO to MAX_ENTROPY_RATIO)
+/// * `spread_cents` - Standard deviation of pitch perception in cents
+///   (17 is typical; at most MAX_ENTROPY_SPREAD)
+/// * `max_denominator` - Largest denominator considered (1 to MAX_ENTROPY_DENOMINATOR)
+///
+/// # Returns
+/// Entropy in bits
+///
+/// # Panics
+/// Panics if any argument is outside its range
+pub fn harmonic_entropy(ratio: f64, spread_cents: f64, max_denominator: u64) -> f64 {
+    if !(1.0 / MAX_ENTROPY_RATIO..=MAX_ENTROPY_RATIO).contains(&ratio) {
+        panic!("Ratio must be between 1/16 and 16");
+    }
+    if !(spread_cents > 0.0 && spread_cents <= MAX_ENTROPY_SPREAD) {
+        panic!("Spread must be positive and at most 200 cents");
+    }
+    if !(1..=MAX_ENTROPY_DENOMINATOR).contains(&max_denominator) {
+        panic!("max_denominator must be between 1 and 10000");
+    }
+
+    // Fractions within 6 standard deviations, plus a neighbour on each side
+    let cents = 1200.0 * ratio.log2();
+    let margin = 6.0 * spread_cents;
+    let low = 2.0_f64.powf((cents - margin) / 1200.0);
+    let high = 2.0_f64.powf((cents + margin) / 1200.0);
+
+    let cdf = |x: f64| 0.5 * (1.0 + erf((x - cents) / (spread_cents * std::f64::consts::SQRT_2)));
+    let mediant_cdf = |(a, b): (u64, u64), (c, d): (u64, u64)| {
+        cdf(1200.0 * ((a + c) as f64 / (b + d) as f64).log2())
+    };
+
+    // Step through the Farey sequence of order max_denominator: each term
+    // follows from the two before it
+    let (mut current, mut next) = farey_neighbours(low, max_denominator);
+    let mut lower = 0.0;
+    let mut entropy = 0.0;
+    loop {
+        let last = current.0 as f64 >= high * current.1 as f64;
+        let upper = if last {
+            1.0
+        } else {
+            mediant_cdf(current, next)
+        };
+        let probability = upper - lower;
+        if probability > 0.0 {
+            entropy -= probability * probability.log2();
+        }
+        if last {
+            return entropy;
+        }
+        lower = upper;
+        let k = (max_denominator + current.1) / next.1;
+        (current, next) = (next, (k * next.0 - current.0, k * next.1 - current.1));
+    }
+}
+
+/// Find the consecutive fractions a/b <= x < c/d with denominators up to
+/// max_denominator, descending the Stern-Brocot tree from the integers around x
+fn farey_neighbours(x: f64, max_denominator: u64) -> ((u64, u64), (u64, u64)) {
+    let whole = x.floor() as u64;
+    let (mut left, mut right) = ((whole, 1), (whole + 1, 1));
+    loop {
+        let mediant = (left.0 + right.0, left.1 + right.1);
+        if mediant.1 > max_denominator {
+            return (left, right);
+        }
+        if mediant.0 as f64 <= x * mediant.1 as f64 {
+            left = mediant;
+        } else {
+            right = mediant;
+        }
+    }
+}
+
+/// Calculate harmonic entropy with a 17 cent spread and denominators up to 100.
+pub fn harmonic_entropy_default(ratio: f64) -> f64 {
+    harmonic_entropy(ratio, 17.0, 100)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frequencies::{
+        midi_to_frequency, A432, SCHUMANN_2ND, SCHUMANN_FUNDAMENTAL, SOLFEGGIO_MI, SOLFEGGIO_UT,
+    };
+
+    #[test]
+    fn test_identify() {
+        // 528 / 396 = 4/3 exactly
+        let fourth = identify_interval(SOLFEGGIO_UT, SOLFEGGIO_MI).unwrap();
+        assert_eq!(fourth.interval, Interval::PerfectFourth);
+        assert_eq!(fourth.octaves, 0);
+        assert!(fourth.cents.abs() < 1e-9);
+
+        let tet_third = identify_interval(A432, midi_to_frequency(73, A432)).unwrap();
+        assert_eq!(tet_third.interval, Interval::MajorThird);
+        assert!((tet_third.cents - 13.686).abs() < 1e-3);
+        assert_eq!(tet_third.to_string(), "major third +13.7c");
+
+        let down = identify_interval(A432, A432 / 3.0).unwrap();
+        assert!(down.descending);
+        assert_eq!(down.ratio(), Some(Fraction::new(3, 1)));
+
+        // Ratios beyond u64 are still named, but have no exact fraction
+        let huge = identify_interval(1.0, 1e25).unwrap();
+        assert_eq!(huge.octaves, 83);
+        assert_eq!(huge.ratio(), None);
+        assert_eq!(down.to_string(), "perfect twelfth down +0.0c");
+        assert!(identify_interval(0.0, A432).is_none());
+    }
+
+    #[test]
+    fn test_compound_and_octaves() {
+        let tenth = identify_ratio(2.5).unwrap();
+        assert_eq!((tenth.interval, tenth.octaves), (Interval::MajorThird, 1));
+        assert_eq!(tenth.to_string(), "major tenth +0.0c");
+
+        let octave = identify_ratio(2.0 * 1.001).unwrap();
+        assert_eq!((octave.interval, octave.octaves), (Interval::Octave, 0));
+        let double = identify_ratio(3.99).unwrap();
+        assert_eq!((double.interval, double.octaves), (Interval::Octave, 1));
+        assert!(identify_ratio(6.0)
+            .unwrap()
+            .to_string()
+            .starts_with("perfect fifth + 2 octaves"));
+
+        // Schumann 2nd / fundamental ≈ 1.826: closest to a minor seventh
+        let schumann = identify_interval(SCHUMANN_FUNDAMENTAL, SCHUMANN_2ND).unwrap();
+        assert_eq!(schumann.interval, Interval::MinorSeventh);
+        assert!(identify_ratio(-1.0).is_none());
+    }
+
+    #[test]
+    fn test_tenney_and_euler() {
+        assert_eq!(tenney_height(Fraction::new(1, 1)), 0.0);
+        assert_eq!(tenney_height(Fraction::new(4, 2)), 1.0);
+        assert!((tenney_height(Fraction::new(3, 2)) - 6.0_f64.log2()).abs() < 1e-12);
+
+        assert_eq!(euler_gradus(Fraction::new(1, 1)), 1);
+        assert_eq!(euler_gradus(Fraction::new(2, 1)), 2);
+        assert_eq!(euler_gradus(Fraction::new(3, 2)), 4);
+        assert_eq!(euler_gradus(Fraction::new(5, 4)), 7);
+        assert_eq!(euler_gradus(Fraction::new(45, 32)), 14);
+        // Largest primes below 2³² and 2⁶⁴, and their 64-bit semiprime
+        let prime = 4_294_967_291;
+        let other = 4_294_967_279;
+        assert_eq!(euler_gradus(Fraction::new(prime, 1)), prime);
+        assert_eq!(euler_gradus(Fraction::new(prime * 2, 3)), prime + 3);
+        assert_eq!(
+            euler_gradus(Fraction::new(prime * other, 1)),
+            prime + other - 1
+        );
+        let largest = 18_446_744_073_709_551_557;
+        assert_eq!(euler_gradus(Fraction::new(largest, 1)), largest);
+        assert_eq!(euler_gradus(Fraction::new(largest, other)), u64::MAX);
+
+        let ranked: Vec<u64> = Interval::ALL
+            .iter()
+            .map(|i| euler_gradus(i.ratio()))
+            .collect();
+        assert!(ranked[7] < ranked[4] && ranked[4] < ranked[1]);
+    }
+
+    #[test]
+    fn test_harmonic_entropy() {
+        let fifth = harmonic_entropy_default(1.5);
+        let tritone = harmonic_entropy_default(2.0_f64.powf(0.5));
+        let octave = harmonic_entropy_default(2.0);
+        assert!(octave < fifth);
+        assert!(fifth < tritone);
+        assert!(fifth > 0.0);
+
+        // The 12-TET fifth sits just off the 3/2 minimum
+        let tet_fifth = harmonic_entropy_default(2.0_f64.powf(7.0 / 12.0));
+        assert!(tet_fifth > fifth);
+        assert!(tet_fifth < tritone);
+
+        // The lazy Farey walk matches collecting and sorting every fraction
+        let (ratio, spread, max_denominator) = (1.3, 25.0, 40);
+        let cents = 1200.0 * f64::log2(ratio);
+        let cdf = |x: f64| 0.5 * (1.0 + erf((x - cents) / (spread * std::f64::consts::SQRT_2)));
+        let mut fractions: Vec<(u64, u64)> = (1..=max_denominator)
+            .flat_map(|q| (1..=4 * q).map(move |p| (p, q)))
+            .filter(|&(p, q)| gcd(p as u128, q as u128) == 1)
+            .collect();
+        fractions.sort_by(|a, b| (a.0 * b.1).cmp(&(b.0 * a.1)));
+        let bounds: Vec<f64> = fractions
+            .windows(2)
+            .map(|w| cdf(1200.0 * ((w[0].0 + w[1].0) as f64 / (w[0].1 + w[1].1) as f64).log2()))
+            .collect();
+        let expected: f64 = std::iter::once(0.0)
+            .chain(bounds.iter().copied())
+            .zip(bounds.iter().copied().chain(std::iter::once(1.0)))
+            .map(|(lower, upper)| upper - lower)
+            .filter(|&probability| probability > 0.0)
+            .map(|probability| -probability * probability.log2())
+            .sum();
+        let entropy = harmonic_entropy(ratio, spread, max_denominator);
+        // Only the mass beyond six spreads, lumped into the end domains, differs
+        assert!(
+            (entropy - expected).abs() < 1e-6,
+            "{} vs {}",
+            entropy,
+            expected
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Ratio must be between 1/16 and 16")]
+    fn test_harmonic_entropy_range() {
+        harmonic_entropy_default(1e12);
+    }
+}
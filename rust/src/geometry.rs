@@ -0,0 +1,204 @@
+//! Golden-angle geometry - phyllotaxis spirals and Fibonacci-lattice spheres.
+//!
+//! (c) 2025 Anywave Creations
+//! MIT License
+
+use crate::phi::{PHI_SQUARED, TAU};
+
+/// The golden angle in radians, 2π / φ² ≈ 137.5°
+pub const GOLDEN_ANGLE: f64 = TAU / PHI_SQUARED;
+
+/// A point in the plane
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Point2 {
+    /// Horizontal coordinate
+    pub x: f64,
+    /// Vertical coordinate
+    pub y: f64,
+}
+
+impl Point2 {
+    /// Create a new 2D point
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    /// Distance from the origin
+    pub fn norm(&self) -> f64 {
+        self.x.hypot(self.y)
+    }
+}
+
+/// A point in 3D space
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Point3 {
+    /// X coordinate
+    pub x: f64,
+    /// Y coordinate
+    pub y: f64,
+    /// Z coordinate (the polar axis for Fibonacci spheres)
+    pub z: f64,
+}
+
+impl Point3 {
+    /// Create a new 3D point
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Distance from the origin
+    pub fn norm(&self) -> f64 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+}
+
+/// Iterator over Vogel-spiral (sunflower) points filling a disk
+#[derive(Debug, Clone)]
+pub struct VogelSpiral {
+    index: usize,
+    count: usize,
+    scale: f64,
+    offset: Point2,
+}
+
+impl Iterator for VogelSpiral {
+    type Item = Point2;
+
+    fn next(&mut self) -> Option<Point2> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let i = self.index as f64;
+        let radius = self.scale * ((i + 0.5) / self.count as f64).sqrt();
+        let theta = i * GOLDEN_ANGLE;
+        self.index += 1;
+
+        Some(Point2::new(
+            self.offset.x + radius * theta.cos(),
+            self.offset.y + radius * theta.sin(),
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for VogelSpiral {}
+
+/// Iterator over Fibonacci-lattice points on a sphere
+#[derive(Debug, Clone)]
+pub struct FibonacciSphere {
+    index: usize,
+    count: usize,
+    scale: f64,
+    offset: Point3,
+}
+
+impl Iterator for FibonacciSphere {
+    type Item = Point3;
+
+    fn next(&mut self) -> Option<Point3> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let i = self.index as f64;
+        let z = 1.0 - (2.0 * i + 1.0) / self.count as f64;
+        let ring = (1.0 - z * z).sqrt();
+        let theta = i * GOLDEN_ANGLE;
+        self.index += 1;
+
+        Some(Point3::new(
+            self.offset.x + self.scale * ring * theta.cos(),
+            self.offset.y + self.scale * ring * theta.sin(),
+            self.offset.z + self.scale * z,
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for FibonacciSphere {}
+
+/// Generate Vogel-spiral points evenly filling a disk.
+///
+/// Point i sits at radius scale × √((i + 0.5) / count) and angle i × golden angle,
+/// so each point covers an equal area.
+///
+/// # Arguments
+/// * `count` - Number of points
+/// * `scale` - Disk radius
+/// * `offset` - Disk centre (origin if None)
+///
+/// # Returns
+/// Iterator over the 2D points
+pub fn vogel_spiral(count: usize, scale: f64, offset: Option<Point2>) -> VogelSpiral {
+    VogelSpiral {
+        index: 0,
+        count,
+        scale,
+        offset: offset.unwrap_or_default(),
+    }
+}
+
+/// Generate Fibonacci-lattice points evenly covering a sphere.
+///
+/// Point i sits at height z = 1 - (2i + 1) / count and longitude i × golden angle.
+///
+/// # Arguments
+/// * `count` - Number of points
+/// * `scale` - Sphere radius
+/// * `offset` - Sphere centre (origin if None)
+///
+/// # Returns
+/// Iterator over the 3D points
+pub fn fibonacci_sphere(count: usize, scale: f64, offset: Option<Point3>) -> FibonacciSphere {
+    FibonacciSphere {
+        index: 0,
+        count,
+        scale,
+        offset: offset.unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phi::PI;
+
+    #[test]
+    fn test_golden_angle() {
+        assert!((GOLDEN_ANGLE - PI * (3.0 - 5.0_f64.sqrt())).abs() < 1e-12);
+        assert!((GOLDEN_ANGLE.to_degrees() - 137.50776405003785).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_vogel_spiral() {
+        let points: Vec<Point2> = vogel_spiral(100, 2.0, Some(Point2::new(1.0, -1.0))).collect();
+        assert_eq!(points.len(), 100);
+        for p in &points {
+            assert!(Point2::new(p.x - 1.0, p.y + 1.0).norm() <= 2.0);
+        }
+        assert_eq!(vogel_spiral(0, 1.0, None).count(), 0);
+    }
+
+    #[test]
+    fn test_fibonacci_sphere() {
+        let spiral = fibonacci_sphere(500, 3.0, None);
+        assert_eq!(spiral.len(), 500);
+        let points: Vec<Point3> = spiral.collect();
+        for p in &points {
+            assert!((p.norm() - 3.0).abs() < 1e-10);
+        }
+
+        // Points are spread evenly between the hemispheres
+        let north = points.iter().filter(|p| p.z > 0.0).count();
+        assert_eq!(north, 250);
+    }
+}
@@ -3,28 +3,106 @@
 //! (c) 2025 Anywave Creations
 //! MIT License
 
+pub mod continued_fraction;
+pub mod dissonance;
 pub mod frequencies;
+pub mod geometry;
+pub mod materials;
+pub mod metallic;
+pub mod note;
 pub mod phi;
+pub mod phinary;
+pub mod registry;
+pub mod scala;
+pub mod spectrum;
+pub mod synth;
+#[cfg(feature = "serde")]
+pub mod tables;
 pub mod thresholds;
+pub mod tolerance;
+pub mod tuning;
+pub mod wav;
+pub mod zphi;
 
 // Re-export commonly used items at crate root
+pub use continued_fraction::{
+    best_rational_approximation, classify_ratio, ContinuedFraction, Fraction, RatioClass,
+};
+
+pub use dissonance::{dissonance_curve, local_minima, pair_roughness, roughness, DissonancePoint};
+
 pub use frequencies::{
-    cents_difference, harmonic_of, octave_of, MaterialFrequency, MaterialProperties, A432, A440,
-    SCHUMANN_2ND, SCHUMANN_3RD, SCHUMANN_4TH, SCHUMANN_5TH, SCHUMANN_FUNDAMENTAL,
-    SCHUMANN_HARMONICS, SOLFEGGIO_FA, SOLFEGGIO_FREQUENCIES, SOLFEGGIO_LA, SOLFEGGIO_MI,
-    SOLFEGGIO_RE, SOLFEGGIO_SOL, SOLFEGGIO_UT,
+    cents_difference, frequencies_match, frequency_to_midi, goertzel, harmonic_of,
+    matching_harmonic, matching_octave, midi_to_frequency, octave_of, pitch_bend_value,
+    GoertzelDetector, MaterialFrequency, MaterialProperties, MidiPitch, TonePower, A432, A440,
+    MIDI_A4, PITCH_BEND_CENTER, PITCH_BEND_MAX, SCHUMANN_2ND, SCHUMANN_3RD, SCHUMANN_4TH,
+    SCHUMANN_5TH, SCHUMANN_FUNDAMENTAL, SCHUMANN_HARMONICS, SOLFEGGIO_FA, SOLFEGGIO_FREQUENCIES,
+    SOLFEGGIO_LA, SOLFEGGIO_MI, SOLFEGGIO_RE, SOLFEGGIO_SOL, SOLFEGGIO_UT,
+};
+
+pub use frequencies::interval::{
+    euler_gradus, harmonic_entropy, harmonic_entropy_default, identify_interval, identify_ratio,
+    tenney_height, Interval, IntervalMatch, MAX_ENTROPY_DENOMINATOR, MAX_ENTROPY_RATIO,
+    MAX_ENTROPY_SPREAD,
+};
+
+pub use geometry::{fibonacci_sphere, vogel_spiral, Point2, Point3, GOLDEN_ANGLE};
+
+pub use materials::{BlendRules, Composite, MaterialCatalog, MaterialError, MixFn, MixRule};
+
+pub use metallic::{
+    is_metallic_ratio, is_metallic_ratio_default, is_plastic_ratio, is_plastic_ratio_default,
+    metallic_mean, metallic_power, metallic_sequence_ratio, padovan_ratio, pell_ratio,
+    plastic_power, BRONZE_RATIO, PLASTIC_INVERSE, PLASTIC_NUMBER, SILVER_RATIO,
 };
 
+pub use note::{format_frequency, Letter, Note, ParseNoteError};
+
 pub use phi::{
-    fibonacci_ratio, is_phi_ratio, is_phi_ratio_default, phi_power, E, PHI, PHI_INVERSE,
-    PHI_SQUARED, PI, SQRT_2, SQRT_3, SQRT_5, TAU,
+    band_frequency_range, brent_maximize, brent_minimize, compute_multiwave_coherence,
+    compute_multiwave_coherence_default, fibonacci, fibonacci_ratio, fibonacci_sequence,
+    frequency_to_band, golden_section_maximize, golden_section_minimize, is_phi_ratio,
+    is_phi_ratio_default, lucas, lucas_sequence, phi_power, BandMeasurement, FibonacciIter,
+    MultiwaveCoherence, OptimizeResult, PhiBand, PhiBandInfo, SequenceOverflow, ANKH, E,
+    FIBONACCI_MAX_INDEX, GREEN_PHI, LUCAS_MAX_INDEX, PHI, PHI_INVERSE, PHI_NEG1, PHI_NEG2,
+    PHI_NEG3, PHI_NEG4, PHI_SQUARED, PI, RA, SCARAB, SQRT_2, SQRT_3, SQRT_5, TAU,
+};
+
+pub use phinary::{from_zeckendorf, zeckendorf, EncodingError, Phinary};
+
+pub use registry::{
+    known_frequencies, known_frequency, known_within, nearest_known, FrequencyCategory,
+    FrequencyMatch, KnownFrequency, MAX_OCTAVE_SHIFT,
+};
+
+pub use scala::{KeyboardMapping, ScalaError, ScalaErrorKind, ScalaPitch, ScalaScale};
+
+pub use spectrum::{
+    fft, nearest_note, rfft, segment_length_for, Complex, NoteMatch, Peak, Spectrum, Window,
+};
+
+pub use synth::{interleave, to_i16, Envelope, Partial, Synth};
+
+#[cfg(feature = "serde")]
+pub use tables::{
+    format_frequencies, format_materials, load_frequencies, load_materials, parse_frequencies,
+    parse_materials, save_frequencies, save_materials, FrequencyRecord, MaterialRecord, TableError,
+    TableErrorKind, TableFormat,
 };
 
 pub use thresholds::{
     coherence_delta, is_coherence_stable, is_coherence_stable_default, normalize_coherence,
-    CoherenceBand, CoherenceLevel, HIGH_COHERENCE, LOW_COHERENCE, MEDIUM_COHERENCE,
+    CoherenceBand, CoherenceLevel, ConsentState, HIGH_COHERENCE, LOW_COHERENCE, MEDIUM_COHERENCE,
     MINIMUM_COHERENCE,
 };
 
+pub use tolerance::Tolerance;
+
+pub use tuning::{EqualTemperament, ScaleTuning, Tuning};
+
+pub use wav::{load_wav, read_wav, save_wav, write_wav, SampleFormat, WavError, WavSpec};
+
+pub use zphi::{QPhi, ZPhi};
+
 /// Library version
 pub const VERSION: &str = "0.1.0";
@@ -0,0 +1,451 @@
+//! Material catalogue - built-in and user-defined resonant materials, and blends of them.
+//!
+//! (c) 2025 Anywave Creations
+//! MIT License
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::frequencies::{cents_difference, MaterialFrequency, MaterialProperties};
+
+/// Error returned when a material cannot be added to a catalogue
+#[derive(Debug, Clone, PartialEq)]
+pub enum MaterialError {
+    /// Name is empty or only whitespace
+    EmptyName,
+    /// Frequency is not a positive finite number
+    InvalidFrequency(f64),
+    /// Alpha affinity outside 0-1
+    InvalidAlphaAffinity(f64),
+    /// Conductivity outside 0-1
+    InvalidConductivity(f64),
+}
+
+impl fmt::Display for MaterialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyName => write!(f, "material name is empty"),
+            Self::InvalidFrequency(v) => write!(f, "frequency {} must be positive", v),
+            Self::InvalidAlphaAffinity(v) => {
+                write!(f, "alpha affinity {} must be between 0 and 1", v)
+            }
+            Self::InvalidConductivity(v) => {
+                write!(f, "conductivity {} must be between 0 and 1", v)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MaterialError {}
+
+impl MaterialProperties {
+    /// Check that the frequency is positive and the 0-1 factors are in range
+    pub fn validate(&self) -> Result<(), MaterialError> {
+        if !(self.frequency.is_finite() && self.frequency > 0.0) {
+            return Err(MaterialError::InvalidFrequency(self.frequency));
+        }
+        if !(0.0..=1.0).contains(&self.alpha_affinity) {
+            return Err(MaterialError::InvalidAlphaAffinity(self.alpha_affinity));
+        }
+        if !(0.0..=1.0).contains(&self.conductivity) {
+            return Err(MaterialError::InvalidConductivity(self.conductivity));
+        }
+        Ok(())
+    }
+}
+
+impl From<MaterialFrequency> for MaterialProperties {
+    fn from(material: MaterialFrequency) -> Self {
+        material.properties()
+    }
+}
+
+/// Built-in materials can be used wherever a catalogue key is expected
+impl AsRef<str> for MaterialFrequency {
+    fn as_ref(&self) -> &str {
+        self.name()
+    }
+}
+
+/// Named materials, starting with the built-in `MaterialFrequency` table.
+///
+/// Names are matched ignoring ASCII case and keep their insertion order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaterialCatalog {
+    entries: Vec<(String, MaterialProperties)>,
+}
+
+impl MaterialCatalog {
+    /// Create a catalogue holding the built-in materials
+    pub fn new() -> Self {
+        Self {
+            entries: MaterialFrequency::ALL
+                .iter()
+                .map(|m| (m.name().to_string(), m.properties()))
+                .collect(),
+        }
+    }
+
+    /// Create a catalogue with no materials
+    pub const fn empty() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|(n, _)| n.eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Add or replace a material.
+    ///
+    /// # Arguments
+    /// * `name` - Material name (e.g. "Amethyst")
+    /// * `properties` - Frequency and 0-1 factors
+    ///
+    /// # Returns
+    /// The properties previously stored under the name, or an error if the
+    /// name is empty or the properties are out of range
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        properties: MaterialProperties,
+    ) -> Result<Option<MaterialProperties>, MaterialError> {
+        let name = name.into().trim().to_string();
+        if name.is_empty() {
+            return Err(MaterialError::EmptyName);
+        }
+        properties.validate()?;
+
+        Ok(match self.position(&name) {
+            Some(i) => Some(std::mem::replace(&mut self.entries[i].1, properties)),
+            None => {
+                self.entries.push((name, properties));
+                None
+            }
+        })
+    }
+
+    /// Look up a material by name or built-in `MaterialFrequency`
+    pub fn get(&self, key: impl AsRef<str>) -> Option<&MaterialProperties> {
+        self.position(key.as_ref()).map(|i| &self.entries[i].1)
+    }
+
+    /// Check if a material is in the catalogue
+    pub fn contains(&self, key: impl AsRef<str>) -> bool {
+        self.position(key.as_ref()).is_some()
+    }
+
+    /// Remove a material, returning its properties
+    pub fn remove(&mut self, key: impl AsRef<str>) -> Option<MaterialProperties> {
+        self.position(key.as_ref())
+            .map(|i| self.entries.remove(i).1)
+    }
+
+    /// Number of materials
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the catalogue has no materials
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over (name, properties) in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &MaterialProperties)> {
+        self.entries.iter().map(|(n, p)| (n.as_str(), p))
+    }
+}
+
+impl Default for MaterialCatalog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Custom mixing function over (value, weight) pairs
+pub type MixFn = dyn Fn(&[(f64, f64)]) -> f64 + Send + Sync;
+
+/// How one property of a composite is derived from its constituents
+#[derive(Clone)]
+pub enum MixRule {
+    /// Weighted geometric mean, the natural average for frequencies
+    GeometricMean,
+    /// Weighted arithmetic mean
+    WeightedMean,
+    /// Custom rule given (value, weight) pairs with weights summing to 1
+    Custom(Arc<MixFn>),
+}
+
+impl MixRule {
+    /// Wrap a closure as a custom rule
+    pub fn custom(rule: impl Fn(&[(f64, f64)]) -> f64 + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(rule))
+    }
+
+    /// Apply the rule to (value, normalized weight) pairs
+    pub fn mix(&self, values: &[(f64, f64)]) -> f64 {
+        match self {
+            Self::GeometricMean => values.iter().map(|(v, w)| w * v.ln()).sum::<f64>().exp(),
+            Self::WeightedMean => values.iter().map(|(v, w)| w * v).sum(),
+            Self::Custom(rule) => rule(values),
+        }
+    }
+}
+
+impl fmt::Debug for MixRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::GeometricMean => write!(f, "GeometricMean"),
+            Self::WeightedMean => write!(f, "WeightedMean"),
+            Self::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+/// Mixing rule for each field of `MaterialProperties`
+#[derive(Debug, Clone)]
+pub struct BlendRules {
+    /// Rule for the base frequency (Hz)
+    pub frequency: MixRule,
+    /// Rule for the alpha affinity (0-1)
+    pub alpha_affinity: MixRule,
+    /// Rule for the conductivity (0-1)
+    pub conductivity: MixRule,
+}
+
+impl Default for BlendRules {
+    /// Geometric mean frequency, weighted mean affinity and conductivity
+    fn default() -> Self {
+        Self {
+            frequency: MixRule::GeometricMean,
+            alpha_affinity: MixRule::WeightedMean,
+            conductivity: MixRule::WeightedMean,
+        }
+    }
+}
+
+/// A weighted composite of materials, e.g. 70% copper + 30% gold
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Composite {
+    parts: Vec<(MaterialProperties, f64)>,
+}
+
+impl Composite {
+    /// Create an empty composite
+    pub const fn new() -> Self {
+        Self { parts: Vec::new() }
+    }
+
+    /// Return this composite with another constituent added.
+    ///
+    /// # Arguments
+    /// * `material` - A built-in `MaterialFrequency` or any `MaterialProperties`
+    /// * `weight` - Relative share; weights need not sum to 1 (70.0 and 30.0 work)
+    ///
+    /// # Panics
+    /// Panics if weight is negative or not finite, or if the material fails
+    /// `MaterialProperties::validate`
+    pub fn with(mut self, material: impl Into<MaterialProperties>, weight: f64) -> Self {
+        if !(weight.is_finite() && weight >= 0.0) {
+            panic!("Weight must be non-negative");
+        }
+        let material = material.into();
+        if let Err(err) = material.validate() {
+            panic!("Material must be valid: {}", err);
+        }
+        self.parts.push((material, weight));
+        self
+    }
+
+    /// Constituents and their weights as given
+    pub fn parts(&self) -> &[(MaterialProperties, f64)] {
+        &self.parts
+    }
+
+    /// Pair one field of each constituent with its normalized weight
+    fn weighted(&self, field: impl Fn(&MaterialProperties) -> f64) -> Option<Vec<(f64, f64)>> {
+        let total: f64 = self.parts.iter().map(|(_, w)| w).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        Some(
+            self.parts
+                .iter()
+                .filter(|(_, w)| *w > 0.0)
+                .map(|(p, w)| (field(p), w / total))
+                .collect(),
+        )
+    }
+
+    /// Blend the constituents with the default rules
+    ///
+    /// # Returns
+    /// The blended properties, or None if the total weight is zero
+    pub fn properties(&self) -> Option<MaterialProperties> {
+        self.properties_with(&BlendRules::default())
+    }
+
+    /// Blend the constituents with chosen rules.
+    ///
+    /// Custom rules may produce values outside 0-1; use
+    /// `MaterialProperties::validate` to check.
+    ///
+    /// # Returns
+    /// The blended properties, or None if the total weight is zero
+    pub fn properties_with(&self, rules: &BlendRules) -> Option<MaterialProperties> {
+        Some(MaterialProperties::new(
+            rules.frequency.mix(&self.weighted(|p| p.frequency)?),
+            rules
+                .alpha_affinity
+                .mix(&self.weighted(|p| p.alpha_affinity)?),
+            rules.conductivity.mix(&self.weighted(|p| p.conductivity)?),
+        ))
+    }
+
+    /// Pitch distance in cents between the lowest and highest constituent
+    /// frequencies (0 for fewer than two weighted constituents)
+    pub fn cents_spread(&self) -> f64 {
+        let frequencies = self
+            .parts
+            .iter()
+            .filter(|(_, w)| *w > 0.0)
+            .map(|(p, _)| p.frequency);
+        let (low, high) = frequencies.fold((f64::INFINITY, 0.0_f64), |(lo, hi), f| {
+            (lo.min(f), hi.max(f))
+        });
+        if high > low {
+            cents_difference(low, high)
+        } else {
+            0.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtins() {
+        let catalog = MaterialCatalog::new();
+        assert_eq!(catalog.len(), 8);
+        assert_eq!(
+            catalog.get(MaterialFrequency::Gold),
+            Some(&MaterialFrequency::Gold.properties())
+        );
+        assert_eq!(catalog.get("quartz").unwrap().frequency, 32768.0);
+        assert!(catalog.contains(MaterialFrequency::Limestone));
+
+        let names: Vec<&str> = catalog.iter().map(|(n, _)| n).collect();
+        assert_eq!(names[0], "Quartz");
+        assert_eq!(names[7], "Limestone");
+        assert!(MaterialCatalog::empty().is_empty());
+    }
+
+    #[test]
+    fn test_user_materials() {
+        let mut catalog = MaterialCatalog::new();
+        let amethyst = MaterialProperties::new(28672.0, 0.88, 0.2);
+        assert_eq!(catalog.insert("Amethyst", amethyst), Ok(None));
+        assert_eq!(catalog.get("AMETHYST"), Some(&amethyst));
+        assert_eq!(catalog.iter().last().unwrap().0, "Amethyst");
+
+        // Replacing a built-in with a measured value keeps its position
+        let measured = MaterialProperties::new(16390.0, 0.8, 0.85);
+        let previous = catalog.insert(" copper ", measured).unwrap();
+        assert_eq!(previous, Some(MaterialFrequency::Copper.properties()));
+        assert_eq!(catalog.get(MaterialFrequency::Copper), Some(&measured));
+        assert_eq!(catalog.len(), 9);
+
+        assert_eq!(catalog.remove("amethyst"), Some(amethyst));
+        assert_eq!(catalog.remove("amethyst"), None);
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut catalog = MaterialCatalog::empty();
+        assert_eq!(
+            catalog.insert("Brass", MaterialProperties::new(10000.0, 1.2, 0.5)),
+            Err(MaterialError::InvalidAlphaAffinity(1.2))
+        );
+        assert_eq!(
+            catalog.insert("Brass", MaterialProperties::new(10000.0, 0.5, -0.1)),
+            Err(MaterialError::InvalidConductivity(-0.1))
+        );
+        assert_eq!(
+            catalog.insert("Brass", MaterialProperties::new(0.0, 0.5, 0.5)),
+            Err(MaterialError::InvalidFrequency(0.0))
+        );
+        assert_eq!(
+            catalog.insert("  ", MaterialProperties::new(1.0, 0.5, 0.5)),
+            Err(MaterialError::EmptyName)
+        );
+        assert!(catalog.is_empty());
+
+        for material in MaterialFrequency::ALL {
+            assert!(material.properties().validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_composite() {
+        let bronze = Composite::new()
+            .with(MaterialFrequency::Copper, 70.0)
+            .with(MaterialFrequency::Gold, 30.0);
+        let blended = bronze.properties().unwrap();
+
+        // 16384^0.7 × 24576^0.3
+        let expected = (0.7 * 16384.0_f64.ln() + 0.3 * 24576.0_f64.ln()).exp();
+        assert!((blended.frequency - expected).abs() < 1e-9);
+        assert!((blended.alpha_affinity - (0.7 * 0.8 + 0.3 * 0.95)).abs() < 1e-12);
+        assert!((blended.conductivity - (0.7 * 0.85 + 0.3 * 0.95)).abs() < 1e-12);
+        assert!(blended.validate().is_ok());
+
+        // 24576 / 16384 is a pure fifth
+        assert!((bronze.cents_spread() - 701.955).abs() < 1e-3);
+        assert_eq!(bronze.parts().len(), 2);
+    }
+
+    #[test]
+    fn test_blend_rules() {
+        let layered = Composite::new()
+            .with(MaterialFrequency::Quartz, 1.0)
+            .with(MaterialProperties::new(28672.0, 0.88, 0.2), 1.0)
+            .with(MaterialFrequency::Iron, 0.0);
+        assert_eq!(layered.cents_spread(), cents_difference(28672.0, 32768.0));
+
+        let rules = BlendRules {
+            frequency: MixRule::WeightedMean,
+            conductivity: MixRule::custom(|values| {
+                values.iter().map(|(v, _)| *v).fold(f64::INFINITY, f64::min)
+            }),
+            ..BlendRules::default()
+        };
+        let blended = layered.properties_with(&rules).unwrap();
+        assert_eq!(blended.frequency, 30720.0);
+        assert_eq!(blended.conductivity, 0.2);
+        assert!((blended.alpha_affinity - 0.89).abs() < 1e-12);
+
+        assert!(Composite::new().properties().is_none());
+        assert!(Composite::new()
+            .with(MaterialFrequency::Gold, 0.0)
+            .properties()
+            .is_none());
+        assert_eq!(
+            Composite::new()
+                .with(MaterialFrequency::Gold, 1.0)
+                .cents_spread(),
+            0.0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Material must be valid")]
+    fn test_composite_rejects_invalid_material() {
+        let _ = Composite::new().with(MaterialProperties::new(-432.0, 0.5, 0.5), 1.0);
+    }
+}
@@ -0,0 +1,242 @@
+//! Metallic means and the plastic number - self-similar scalings beyond φ.
+//!
+//! (c) 2025 Anywave Creations
+//! MIT License
+
+use crate::phi::is_ratio;
+use crate::tolerance::Tolerance;
+
+/// Silver ratio (δ_S) = 1 + √2, the second metallic mean
+pub const SILVER_RATIO: f64 = 2.414213562373095;
+
+/// Bronze ratio = (3 + √13) / 2, the third metallic mean
+pub const BRONZE_RATIO: f64 = 3.302775637731995;
+
+/// Plastic number (ρ), the real root of x³ = x + 1
+pub const PLASTIC_NUMBER: f64 = 1.324717957244746;
+
+/// Inverse of the plastic number (1/ρ) = ρ² - 1
+pub const PLASTIC_INVERSE: f64 = 0.7548776662466927;
+
+/// Calculate the nth metallic mean, the positive root of x² = nx + 1.
+///
+/// # Arguments
+/// * `n` - Order (1 = golden, 2 = silver, 3 = bronze, ...)
+///
+/// # Returns
+/// (n + √(n² + 4)) / 2
+pub fn metallic_mean(n: u32) -> f64 {
+    let n = n as f64;
+    (n + (n * n + 4.0).sqrt()) / 2.0
+}
+
+/// Calculate the kth power of the nth metallic mean.
+///
+/// Negative powers use the inverse 1/m = m - n.
+///
+/// # Arguments
+/// * `n` - Metallic mean order
+/// * `k` - The power (can be negative)
+///
+/// # Returns
+/// The nth metallic mean raised to the power k
+pub fn metallic_power(n: u32, k: i32) -> f64 {
+    let mean = metallic_mean(n);
+    match k {
+        0 => 1.0,
+        1 => mean,
+        k if k > 0 => mean.powi(k),
+        k => (mean - n as f64).powi(-k),
+    }
+}
+
+/// Index past which metallic sequence ratios equal the mean in f64 (φ^(-80) < 2^(-55))
+const METALLIC_CONVERGED_INDEX: u32 = 40;
+
+/// Index past which Padovan ratios equal ρ in f64; the other roots of
+/// x³ = x + 1 have modulus ρ^(-1/2), so errors shrink by ρ^(-3/2) per step
+const PADOVAN_CONVERGED_INDEX: u32 = 100;
+
+/// Calculate the ratio x(k+1)/x(k) of the nth metallic sequence.
+///
+/// The sequence x(k+1) = n × x(k) + x(k-1) with x(0) = 0, x(1) = 1 gives the
+/// Fibonacci numbers for n = 1 and the Pell numbers for n = 2. Its ratios
+/// approach the nth metallic mean, closing in by a factor of at least φ² per
+/// step; past k = 40 they equal the mean to f64 precision, which is returned
+/// directly.
+///
+/// # Arguments
+/// * `n` - Metallic mean order (must be >= 1)
+/// * `k` - Sequence index (must be >= 1)
+///
+/// # Returns
+/// The ratio of consecutive sequence terms
+///
+/// # Panics
+/// Panics if n < 1 or k < 1
+pub fn metallic_sequence_ratio(n: u32, k: u32) -> f64 {
+    if n < 1 {
+        panic!("n must be >= 1");
+    }
+    if k < 1 {
+        panic!("k must be >= 1");
+    }
+
+    if k > METALLIC_CONVERGED_INDEX {
+        return metallic_mean(n);
+    }
+
+    // x(k+1)/x(k) = n + x(k-1)/x(k), iterated from x(2)/x(1) = n
+    let n = n as f64;
+    let mut ratio = n;
+    for _ in 1..k {
+        ratio = n + 1.0 / ratio;
+    }
+    ratio
+}
+
+/// Calculate the ratio P(k+1)/P(k) of Pell numbers, which approaches the silver ratio.
+///
+/// # Panics
+/// Panics if k < 1
+pub fn pell_ratio(k: u32) -> f64 {
+    metallic_sequence_ratio(2, k)
+}
+
+/// Check if two values are in the nth metallic ratio.
+///
+/// # Arguments
+/// * `a` - First value
+/// * `b` - Second value
+/// * `n` - Metallic mean order
+/// * `tolerance` - Acceptable deviation from the metallic mean (a bare f64 is absolute)
+///
+/// # Returns
+/// True if the larger over the smaller value is approximately the metallic mean
+pub fn is_metallic_ratio(a: f64, b: f64, n: u32, tolerance: impl Into<Tolerance>) -> bool {
+    is_ratio(a, b, metallic_mean(n), tolerance)
+}
+
+/// Check if two values are in the nth metallic ratio with default tolerance of 0.01.
+pub fn is_metallic_ratio_default(a: f64, b: f64, n: u32) -> bool {
+    is_metallic_ratio(a, b, n, 0.01)
+}
+
+/// Calculate ρ^k for the plastic number.
+///
+/// # Arguments
+/// * `k` - The power (can be negative)
+///
+/// # Returns
+/// ρ raised to the power k
+pub fn plastic_power(k: i32) -> f64 {
+    match k {
+        0 => 1.0,
+        1 => PLASTIC_NUMBER,
+        -1 => PLASTIC_INVERSE,
+        k if k > 0 => PLASTIC_NUMBER.powi(k),
+        k => PLASTIC_INVERSE.powi(-k),
+    }
+}
+
+/// Calculate the ratio P(k+1)/P(k) of Padovan numbers, which approaches ρ.
+///
+/// Uses P(0) = P(1) = P(2) = 1 and P(k) = P(k-2) + P(k-3). Past k = 100 the
+/// ratio equals ρ to f64 precision, which is returned directly.
+///
+/// # Arguments
+/// * `k` - The Padovan index (must be >= 1)
+///
+/// # Returns
+/// The ratio of consecutive Padovan numbers
+///
+/// # Panics
+/// Panics if k < 1
+pub fn padovan_ratio(k: u32) -> f64 {
+    if k < 1 {
+        panic!("k must be >= 1");
+    }
+    if k > PADOVAN_CONVERGED_INDEX {
+        return PLASTIC_NUMBER;
+    }
+
+    // Window (P(i-2), P(i-1), P(i)) from i = 1 with P(-1) = 0,
+    // rescaled each step to stay in range
+    let (mut a, mut b, mut c) = (0.0_f64, 1.0_f64, 1.0_f64);
+    for _ in 1..k {
+        (a, b, c) = (b / c, 1.0, (a + b) / c);
+    }
+    (a + b) / c
+}
+
+/// Check if two values are in the plastic ratio.
+///
+/// # Arguments
+/// * `a` - First value
+/// * `b` - Second value
+/// * `tolerance` - Acceptable deviation from ρ (a bare f64 is absolute)
+///
+/// # Returns
+/// True if the larger over the smaller value is approximately ρ
+pub fn is_plastic_ratio(a: f64, b: f64, tolerance: impl Into<Tolerance>) -> bool {
+    is_ratio(a, b, PLASTIC_NUMBER, tolerance)
+}
+
+/// Check if two values are in the plastic ratio with default tolerance of 0.01.
+pub fn is_plastic_ratio_default(a: f64, b: f64) -> bool {
+    is_plastic_ratio(a, b, 0.01)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phi::{fibonacci_ratio, PHI, PHI_INVERSE};
+
+    #[test]
+    fn test_metallic_means() {
+        assert!((metallic_mean(1) - PHI).abs() < 1e-15);
+        assert!((metallic_mean(2) - SILVER_RATIO).abs() < 1e-15);
+        assert!((metallic_mean(3) - BRONZE_RATIO).abs() < 1e-15);
+        assert_eq!(metallic_mean(0), 1.0);
+        assert!((metallic_power(1, -1) - PHI_INVERSE).abs() < 1e-15);
+        assert!((metallic_power(2, 2) - (2.0 * SILVER_RATIO + 1.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sequence_ratios() {
+        for k in 1..30 {
+            assert!((metallic_sequence_ratio(1, k) - fibonacci_ratio(k)).abs() < 1e-12);
+        }
+        assert_eq!(pell_ratio(2), 2.5); // 5 / 2
+        assert!((pell_ratio(40) - SILVER_RATIO).abs() < 1e-12);
+
+        // The iteration has converged before the closed form takes over
+        for n in [1, 2, 3, 1000] {
+            let last = metallic_sequence_ratio(n, METALLIC_CONVERGED_INDEX);
+            assert!((last - metallic_mean(n)).abs() <= 4.0 * f64::EPSILON * last);
+        }
+        assert_eq!(metallic_sequence_ratio(1, u32::MAX), PHI);
+    }
+
+    #[test]
+    fn test_plastic_number() {
+        assert!((PLASTIC_NUMBER.powi(3) - PLASTIC_NUMBER - 1.0).abs() < 1e-12);
+        assert!((plastic_power(-2) * plastic_power(2) - 1.0).abs() < 1e-12);
+        assert_eq!(padovan_ratio(1), 1.0); // 1 / 1
+        assert_eq!(padovan_ratio(2), 2.0); // 2 / 1
+        assert_eq!(padovan_ratio(7), 1.4); // 7 / 5
+        assert!((padovan_ratio(200) - PLASTIC_NUMBER).abs() < 1e-12);
+        assert!((padovan_ratio(100_000) - PLASTIC_NUMBER).abs() < 1e-12);
+        let last = padovan_ratio(PADOVAN_CONVERGED_INDEX);
+        assert!((last - PLASTIC_NUMBER).abs() <= 4.0 * f64::EPSILON);
+        assert_eq!(padovan_ratio(u32::MAX), PLASTIC_NUMBER);
+    }
+
+    #[test]
+    fn test_ratio_checks() {
+        assert!(is_metallic_ratio_default(1.0, SILVER_RATIO, 2));
+        assert!(!is_metallic_ratio_default(1.0, PHI, 2));
+        assert!(is_plastic_ratio_default(PLASTIC_NUMBER, 1.0));
+        assert!(!is_plastic_ratio_default(1.0, 2.0));
+    }
+}
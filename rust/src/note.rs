@@ -0,0 +1,375 @@
+//! Scientific pitch notation - parsing and formatting note names such as "C#3" or "A4+14c".
+//!
+//! (c) 2025 Anywave Creations
+//! MIT License
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::frequencies::MIDI_A4;
+
+/// Natural note letter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Letter {
+    C,
+    D,
+    E,
+    F,
+    G,
+    A,
+    B,
+}
+
+impl Letter {
+    /// Semitones above C
+    pub const fn semitone(&self) -> i32 {
+        match self {
+            Self::C => 0,
+            Self::D => 2,
+            Self::E => 4,
+            Self::F => 5,
+            Self::G => 7,
+            Self::A => 9,
+            Self::B => 11,
+        }
+    }
+
+    /// Parse a letter, ignoring case
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c.to_ascii_uppercase() {
+            'C' => Some(Self::C),
+            'D' => Some(Self::D),
+            'E' => Some(Self::E),
+            'F' => Some(Self::F),
+            'G' => Some(Self::G),
+            'A' => Some(Self::A),
+            'B' => Some(Self::B),
+            _ => None,
+        }
+    }
+
+    /// Upper-case letter
+    pub const fn as_char(&self) -> char {
+        match self {
+            Self::C => 'C',
+            Self::D => 'D',
+            Self::E => 'E',
+            Self::F => 'F',
+            Self::G => 'G',
+            Self::A => 'A',
+            Self::B => 'B',
+        }
+    }
+}
+
+/// Error returned when parsing a note name fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseNoteError {
+    /// Empty string
+    Empty,
+    /// First character is not a note letter
+    InvalidLetter(char),
+    /// More sharps or flats than an i8 can count
+    TooManyAccidentals,
+    /// Octave number missing or malformed
+    InvalidOctave,
+    /// Cents suffix malformed (expected e.g. "+14c")
+    InvalidCents,
+    /// Unexpected characters after the note
+    TrailingCharacters(String),
+}
+
+impl fmt::Display for ParseNoteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty note name"),
+            Self::InvalidLetter(c) => write!(f, "invalid note letter '{}'", c),
+            Self::TooManyAccidentals => write!(f, "too many accidentals"),
+            Self::InvalidOctave => write!(f, "missing or invalid octave number"),
+            Self::InvalidCents => write!(f, "invalid cents suffix"),
+            Self::TrailingCharacters(rest) => write!(f, "unexpected trailing text '{}'", rest),
+        }
+    }
+}
+
+impl std::error::Error for ParseNoteError {}
+
+/// A note in scientific pitch notation with an optional cents offset
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Note {
+    /// Natural letter
+    pub letter: Letter,
+    /// Accidentals: positive = sharps, negative = flats
+    pub accidental: i8,
+    /// Octave number (C4 is middle C)
+    pub octave: i32,
+    /// Offset in cents
+    pub cents: f64,
+}
+
+impl Note {
+    /// Create a note without a cents offset
+    pub const fn new(letter: Letter, accidental: i8, octave: i32) -> Self {
+        Self {
+            letter,
+            accidental,
+            octave,
+            cents: 0.0,
+        }
+    }
+
+    /// Return this note with a cents offset
+    pub const fn with_cents(mut self, cents: f64) -> Self {
+        self.cents = cents;
+        self
+    }
+
+    /// MIDI-style semitone number (C-1 = 0, A4 = 69), may be out of the 0-127 range.
+    ///
+    /// Computed in i64 so that every octave and accidental is representable.
+    pub const fn semitone(&self) -> i64 {
+        (self.octave as i64 + 1) * 12 + self.letter.semitone() as i64 + self.accidental as i64
+    }
+
+    /// Frequency in Hz.
+    ///
+    /// # Arguments
+    /// * `reference` - Frequency of A4 in Hz (e.g. `A432` or `A440`)
+    pub fn frequency(&self, reference: f64) -> f64 {
+        let semitones = (self.semitone() - MIDI_A4 as i64) as f64 + self.cents / 100.0;
+        reference * 2.0_f64.powf(semitones / 12.0)
+    }
+
+    /// Find the nearest note to a frequency, spelled with sharps.
+    ///
+    /// # Arguments
+    /// * `frequency` - Frequency in Hz (must be positive and finite)
+    /// * `reference` - Frequency of A4 in Hz (e.g. `A432` or `A440`; must be
+    ///   positive and finite)
+    ///
+    /// # Returns
+    /// The nearest note, with the remaining offset in cents
+    ///
+    /// # Panics
+    /// Panics if either frequency is not positive and finite
+    pub fn from_frequency(frequency: f64, reference: f64) -> Self {
+        const SPELLING: [(Letter, i8); 12] = [
+            (Letter::C, 0),
+            (Letter::C, 1),
+            (Letter::D, 0),
+            (Letter::D, 1),
+            (Letter::E, 0),
+            (Letter::F, 0),
+            (Letter::F, 1),
+            (Letter::G, 0),
+            (Letter::G, 1),
+            (Letter::A, 0),
+            (Letter::A, 1),
+            (Letter::B, 0),
+        ];
+
+        if !(frequency > 0.0 && frequency.is_finite()) {
+            panic!("Frequency must be positive and finite");
+        }
+        if !(reference > 0.0 && reference.is_finite()) {
+            panic!("Reference must be positive and finite");
+        }
+
+        // Difference of logs, as the quotient can overflow or underflow
+        let exact = MIDI_A4 as f64 + 12.0 * (frequency.log2() - reference.log2());
+        let semitone = exact.round() as i32;
+        let (letter, accidental) = SPELLING[semitone.rem_euclid(12) as usize];
+
+        Self {
+            letter,
+            accidental,
+            octave: semitone.div_euclid(12) - 1,
+            cents: (exact - semitone as f64) * 100.0,
+        }
+    }
+}
+
+impl fmt::Display for Note {
+    /// Formats as e.g. "F##5+14c"; cents are rounded to whole numbers
+    /// unless a precision is given, and omitted when zero
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.letter.as_char())?;
+        let symbol = if self.accidental > 0 { "#" } else { "b" };
+        for _ in 0..self.accidental.unsigned_abs() {
+            write!(f, "{}", symbol)?;
+        }
+        write!(f, "{}", self.octave)?;
+
+        let precision = f.precision().unwrap_or(0);
+        let scale = 10.0_f64.powi(precision as i32);
+        let cents = (self.cents * scale).round() / scale;
+        if cents != 0.0 {
+            write!(f, "{:+.*}c", precision, cents)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Note {
+    type Err = ParseNoteError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.char_indices().peekable();
+
+        let (_, first) = chars.next().ok_or(ParseNoteError::Empty)?;
+        let letter = Letter::from_char(first).ok_or(ParseNoteError::InvalidLetter(first))?;
+
+        let mut accidental: i8 = 0;
+        while let Some(&(_, c)) = chars.peek() {
+            let step = match c {
+                '#' | '♯' => 1,
+                'b' | '♭' => -1,
+                _ => break,
+            };
+            accidental = accidental
+                .checked_add(step)
+                .ok_or(ParseNoteError::TooManyAccidentals)?;
+            chars.next();
+        }
+
+        // Octave: optional minus sign followed by digits
+        let start = chars.peek().map_or(s.len(), |&(i, _)| i);
+        let rest = &s[start..];
+        let sign_len = usize::from(rest.starts_with('-'));
+        let digits = rest[sign_len..]
+            .bytes()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if digits == 0 {
+            return Err(ParseNoteError::InvalidOctave);
+        }
+        let octave_end = sign_len + digits;
+        let octave = rest[..octave_end]
+            .parse()
+            .map_err(|_| ParseNoteError::InvalidOctave)?;
+
+        // Cents: "+14c", "-3.5c"
+        let rest = &rest[octave_end..];
+        let cents = if rest.is_empty() {
+            0.0
+        } else if rest.starts_with(['+', '-']) {
+            let body = rest.strip_suffix('c').ok_or(ParseNoteError::InvalidCents)?;
+            body.parse::<f64>()
+                .ok()
+                .filter(|c| c.is_finite())
+                .ok_or(ParseNoteError::InvalidCents)?
+        } else {
+            return Err(ParseNoteError::TrailingCharacters(rest.to_string()));
+        };
+
+        Ok(Self {
+            letter,
+            accidental,
+            octave,
+            cents,
+        })
+    }
+}
+
+/// Format a frequency as the nearest note name with cents offset.
+///
+/// # Arguments
+/// * `frequency` - Frequency in Hz (must be positive and finite)
+/// * `reference` - Frequency of A4 in Hz (e.g. `A432` or `A440`; must be
+///   positive and finite)
+///
+/// # Returns
+/// A string such as "C5+16c"
+///
+/// # Panics
+/// Panics if either frequency is not positive and finite
+pub fn format_frequency(frequency: f64, reference: f64) -> String {
+    Note::from_frequency(frequency, reference).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frequencies::{A432, A440, SOLFEGGIO_MI};
+
+    #[test]
+    fn test_parse() {
+        let a4: Note = "A4".parse().unwrap();
+        assert_eq!(a4, Note::new(Letter::A, 0, 4));
+        assert_eq!("C#3".parse::<Note>().unwrap().semitone(), 49);
+        assert_eq!(
+            "Bb-1".parse::<Note>().unwrap(),
+            Note::new(Letter::B, -1, -1)
+        );
+
+        let note: Note = "F##5+14c".parse().unwrap();
+        assert_eq!(note, Note::new(Letter::F, 2, 5).with_cents(14.0));
+        assert_eq!("e4-3.5c".parse::<Note>().unwrap().cents, -3.5);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("".parse::<Note>(), Err(ParseNoteError::Empty));
+        assert_eq!(
+            "H4".parse::<Note>(),
+            Err(ParseNoteError::InvalidLetter('H'))
+        );
+        assert_eq!("C#".parse::<Note>(), Err(ParseNoteError::InvalidOctave));
+        assert_eq!("C4+14".parse::<Note>(), Err(ParseNoteError::InvalidCents));
+        assert_eq!(
+            "C4x".parse::<Note>(),
+            Err(ParseNoteError::TrailingCharacters("x".to_string()))
+        );
+        let sharps = format!("C{}4", "#".repeat(128));
+        assert_eq!(
+            sharps.parse::<Note>(),
+            Err(ParseNoteError::TooManyAccidentals)
+        );
+        assert_eq!(
+            format!("C{}4", "b".repeat(128))
+                .parse::<Note>()
+                .unwrap()
+                .accidental,
+            -128
+        );
+
+        let extreme = Note::new(Letter::B, i8::MAX, i32::MAX);
+        assert_eq!(extreme.semitone(), (i32::MAX as i64 + 1) * 12 + 11 + 127);
+    }
+
+    #[test]
+    fn test_frequency_roundtrip() {
+        assert_eq!("A4".parse::<Note>().unwrap().frequency(A432), A432);
+        assert!(("A3+1200c".parse::<Note>().unwrap().frequency(A440) - A440).abs() < 1e-9);
+        assert_eq!(format_frequency(SOLFEGGIO_MI, A440), "C5+16c");
+        assert_eq!(
+            format!("{:.2}", Note::from_frequency(SOLFEGGIO_MI, A440)),
+            "C5+15.64c"
+        );
+        assert_eq!(format_frequency(A432, A432), "A4");
+        assert_eq!(format_frequency(A440 / 16.0 * 1.5, A440), "E1+2c");
+
+        let note: Note = "Gb2-7c".parse().unwrap();
+        let back = Note::from_frequency(note.frequency(A432), A432);
+        assert_eq!(back.semitone(), note.semitone());
+        assert!((back.cents - note.cents).abs() < 1e-9);
+
+        // The ratio of these overflows, but the logs do not
+        let high = Note::from_frequency(f64::MAX, f64::MIN_POSITIVE);
+        assert!(high.cents.is_finite());
+        assert!(high.octave > 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Frequency must be positive and finite")]
+    fn test_from_frequency_rejects_zero() {
+        Note::from_frequency(0.0, A440);
+    }
+
+    #[test]
+    #[should_panic(expected = "Reference must be positive and finite")]
+    fn test_format_frequency_rejects_nan_reference() {
+        format_frequency(A440, f64::NAN);
+    }
+}
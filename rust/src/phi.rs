@@ -3,6 +3,8 @@
 //! (c) 2025 Anywave Creations
 //! MIT License
 
+use crate::tolerance::Tolerance;
+
 /// The golden ratio (φ) = (1 + √5) / 2
 pub const PHI: f64 = 1.618033988749895;
 
@@ -30,6 +32,30 @@ pub const TAU: f64 = std::f64::consts::TAU;
 /// Euler's number (e)
 pub const E: f64 = std::f64::consts::E;
 
+/// φ¹ ≈ 1.618 - Base golden ratio (consent threshold: FULL)
+pub const GREEN_PHI: f64 = PHI;
+
+/// φ³ ≈ 4.236 - Cubic extension
+pub const ANKH: f64 = 4.23606797749979;
+
+/// φ⁵ ≈ 11.090 - Fifth power
+pub const RA: f64 = 11.090169943749475;
+
+/// φ⁷ ≈ 29.034 - Seventh power
+pub const SCARAB: f64 = 29.034441853748632;
+
+/// φ⁻¹ ≈ 0.618 - DIMINISHED_CONSENT threshold
+pub const PHI_NEG1: f64 = PHI_INVERSE;
+
+/// φ⁻² ≈ 0.382 - SUSPENDED_CONSENT threshold
+pub const PHI_NEG2: f64 = 0.38196601125010515;
+
+/// φ⁻³ ≈ 0.236 - EMERGENCY_OVERRIDE threshold
+pub const PHI_NEG3: f64 = 0.2360679774997897;
+
+/// φ⁻⁴ ≈ 0.146 - Critical minimum
+pub const PHI_NEG4: f64 = 0.14589803375031546;
+
 /// Calculate φ^n using the recurrence relation.
 ///
 /// # Arguments
@@ -47,8 +73,152 @@ pub fn phi_power(n: i32) -> f64 {
     }
 }
 
+/// Largest index n for which F(n) fits in a u128
+pub const FIBONACCI_MAX_INDEX: u32 = 186;
+
+/// Largest index n for which L(n) fits in a u128
+pub const LUCAS_MAX_INDEX: u32 = 184;
+
+/// Error returned when a sequence term does not fit in a u128
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SequenceOverflow {
+    /// Name of the sequence
+    pub sequence: &'static str,
+    /// Requested index
+    pub index: u32,
+}
+
+impl std::fmt::Display for SequenceOverflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({}) overflows u128", self.sequence, self.index)
+    }
+}
+
+impl std::error::Error for SequenceOverflow {}
+
+/// Fast-doubling step returning (F(n), F(n+1)), or None on overflow.
+fn fibonacci_pair(n: u32) -> Option<(u128, u128)> {
+    if n == 0 {
+        return Some((0, 1));
+    }
+
+    let (a, b) = fibonacci_pair(n / 2)?;
+    // F(2k) = F(k) × (2F(k+1) - F(k)), F(2k+1) = F(k)² + F(k+1)²
+    let even = a.checked_mul(b.checked_mul(2)?.checked_sub(a)?)?;
+    let odd = a.checked_mul(a)?.checked_add(b.checked_mul(b)?)?;
+
+    if n.is_multiple_of(2) {
+        Some((even, odd))
+    } else {
+        Some((odd, even.checked_add(odd)?))
+    }
+}
+
+/// Calculate the exact Fibonacci number F(n) using fast doubling.
+///
+/// Runs in O(log n) with F(0) = 0, F(1) = 1.
+///
+/// # Arguments
+/// * `n` - The Fibonacci index
+///
+/// # Returns
+/// F(n), or an error if it exceeds u128 (n > 186)
+pub fn fibonacci(n: u32) -> Result<u128, SequenceOverflow> {
+    let overflow = SequenceOverflow {
+        sequence: "F",
+        index: n,
+    };
+
+    if n == 0 {
+        return Ok(0);
+    }
+
+    // Compute F(n) without F(n+1) so that F(186) stays in range
+    let (a, b) = fibonacci_pair(n / 2).ok_or(overflow)?;
+    let value = if n.is_multiple_of(2) {
+        b.checked_mul(2).and_then(|b2| a.checked_mul(b2 - a))
+    } else {
+        a.checked_mul(a)
+            .and_then(|a2| b.checked_mul(b).and_then(|b2| a2.checked_add(b2)))
+    };
+
+    value.ok_or(overflow)
+}
+
+/// Calculate the exact Lucas number L(n).
+///
+/// Uses L(n) = F(n-1) + F(n+1) with L(0) = 2, L(1) = 1.
+///
+/// # Arguments
+/// * `n` - The Lucas index
+///
+/// # Returns
+/// L(n), or an error if it exceeds u128 (n > 184)
+pub fn lucas(n: u32) -> Result<u128, SequenceOverflow> {
+    let overflow = SequenceOverflow {
+        sequence: "L",
+        index: n,
+    };
+
+    if n == 0 {
+        return Ok(2);
+    }
+
+    let (f_n, f_next) = fibonacci_pair(n).ok_or(overflow)?;
+    // F(n-1) = F(n+1) - F(n)
+    f_next.checked_add(f_next - f_n).ok_or(overflow)
+}
+
+/// Iterator over the terms of a Fibonacci-type sequence.
+///
+/// Yields every term that fits in a u128 and then stops.
+#[derive(Debug, Clone)]
+pub struct FibonacciIter {
+    current: Option<u128>,
+    next: Option<u128>,
+}
+
+impl FibonacciIter {
+    /// Create an iterator from the first two terms
+    pub const fn new(first: u128, second: u128) -> Self {
+        Self {
+            current: Some(first),
+            next: Some(second),
+        }
+    }
+}
+
+impl Iterator for FibonacciIter {
+    type Item = u128;
+
+    fn next(&mut self) -> Option<u128> {
+        let current = self.current?;
+        self.current = self.next;
+        self.next = match (current, self.next) {
+            (a, Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        Some(current)
+    }
+}
+
+/// Iterate over the Fibonacci numbers F(0), F(1), F(2), ...
+pub const fn fibonacci_sequence() -> FibonacciIter {
+    FibonacciIter::new(0, 1)
+}
+
+/// Iterate over the Lucas numbers L(0), L(1), L(2), ...
+pub const fn lucas_sequence() -> FibonacciIter {
+    FibonacciIter::new(2, 1)
+}
+
 /// Calculate the ratio F(n+1)/F(n) which approaches φ.
 ///
+/// The ratio is computed from exact Fibonacci numbers while F(n+1) fits in
+/// a u128. Beyond that it uses Binet's formula,
+/// F(n+1)/F(n) = φ + (-1)^n / (φ^n × F(n)) ≈ φ + (-1)^n × √5 × φ^(-2n),
+/// whose approximation error is about √5 × φ^(-4n), far under f64 precision.
+///
 /// # Arguments
 /// * `n` - The Fibonacci index (must be >= 1)
 ///
@@ -62,34 +232,48 @@ pub fn fibonacci_ratio(n: u32) -> f64 {
         panic!("n must be >= 1");
     }
 
-    let mut fib_prev: u64 = 1;
-    let mut fib_curr: u64 = 1;
-
-    for _ in 0..n.saturating_sub(1) {
-        let temp = fib_curr;
-        fib_curr = fib_prev.saturating_add(fib_curr);
-        fib_prev = temp;
+    if n < FIBONACCI_MAX_INDEX {
+        if let Some((f_n, f_next)) = fibonacci_pair(n) {
+            return f_next as f64 / f_n as f64;
+        }
     }
 
-    fib_curr as f64 / fib_prev as f64
+    // φ^(-2n) underflows to zero long before n reaches this cap
+    let exponent = 2 * n.min(1024) as i32;
+    let sign = if n.is_multiple_of(2) { 1.0 } else { -1.0 };
+    PHI + sign * SQRT_5 * PHI_INVERSE.powi(exponent)
 }
 
-/// Check if two values are in golden ratio.
+/// Check if two values are in a given ratio.
 ///
 /// # Arguments
 /// * `a` - First value
-/// * `b` - Second value (should be larger)
-/// * `tolerance` - Acceptable deviation from φ
+/// * `b` - Second value
+/// * `target` - Expected ratio of the larger to the smaller value
+/// * `tolerance` - Acceptable deviation from the target (a bare f64 is absolute)
 ///
 /// # Returns
-/// True if b/a is approximately φ
-pub fn is_phi_ratio(a: f64, b: f64, tolerance: f64) -> bool {
+/// True if max(a, b) / min(a, b) is within tolerance of the target
+pub fn is_ratio(a: f64, b: f64, target: f64, tolerance: impl Into<Tolerance>) -> bool {
     if a <= 0.0 || b <= 0.0 {
         return false;
     }
 
     let ratio = a.max(b) / a.min(b);
-    (ratio - PHI).abs() < tolerance
+    tolerance.into().matches(ratio, target)
+}
+
+/// Check if two values are in golden ratio.
+///
+/// # Arguments
+/// * `a` - First value
+/// * `b` - Second value (should be larger)
+/// * `tolerance` - Acceptable deviation from φ (a bare f64 is absolute)
+///
+/// # Returns
+/// True if b/a is approximately φ
+pub fn is_phi_ratio(a: f64, b: f64, tolerance: impl Into<Tolerance>) -> bool {
+    is_ratio(a, b, PHI, tolerance)
 }
 
 /// Check if two values are in golden ratio with default tolerance of 0.01.
@@ -97,6 +281,491 @@ pub fn is_phi_ratio_default(a: f64, b: f64) -> bool {
     is_phi_ratio(a, b, 0.01)
 }
 
+/// Complete information for a φ-scaled frequency band
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhiBandInfo {
+    /// Band index k in φ^k
+    pub index: i32,
+    /// Centre frequency in Hz (φ^k)
+    pub frequency: f64,
+    /// Period in seconds (1/φ^k)
+    pub period: f64,
+    /// Angular frequency in rad/sec (2π × φ^k)
+    pub omega: f64,
+    /// Amplitude weight φ^(-|k|), symmetric around CORE
+    pub weight: f64,
+    /// Human-readable name
+    pub name: &'static str,
+    /// Physical/biological interpretation
+    pub description: &'static str,
+}
+
+/// The five φ-scaled frequency bands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PhiBand {
+    Ultra,
+    Slow,
+    Core,
+    Fast,
+    Rapid,
+}
+
+impl PhiBand {
+    /// All bands in order from ULTRA to RAPID
+    pub const ALL: [Self; 5] = [Self::Ultra, Self::Slow, Self::Core, Self::Fast, Self::Rapid];
+
+    /// Get the band index k (-2 to +2)
+    pub const fn index(&self) -> i32 {
+        match self {
+            Self::Ultra => -2,
+            Self::Slow => -1,
+            Self::Core => 0,
+            Self::Fast => 1,
+            Self::Rapid => 2,
+        }
+    }
+
+    /// Position of this band in `ALL`
+    const fn position(&self) -> usize {
+        (self.index() + 2) as usize
+    }
+
+    /// Get the band by index (-2 to +2)
+    pub const fn from_index(k: i32) -> Option<Self> {
+        match k {
+            -2 => Some(Self::Ultra),
+            -1 => Some(Self::Slow),
+            0 => Some(Self::Core),
+            1 => Some(Self::Fast),
+            2 => Some(Self::Rapid),
+            _ => None,
+        }
+    }
+
+    /// Get the human-readable name
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Ultra => "ULTRA",
+            Self::Slow => "SLOW",
+            Self::Core => "CORE",
+            Self::Fast => "FAST",
+            Self::Rapid => "RAPID",
+        }
+    }
+
+    /// Get the physical/biological interpretation
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::Ultra => "Ultra-low: Ultradian rhythms, circadian influence",
+            Self::Slow => "Slow: Baroreflex, Mayer waves, blood pressure oscillations",
+            Self::Core => "Core: Reference baseline, ~60 bpm heart rate",
+            Self::Fast => "Fast: Respiratory sinus arrhythmia, breath coupling",
+            Self::Rapid => "Rapid: Fast breathing, startle response, acute stress",
+        }
+    }
+
+    /// Get the centre frequency in Hz (φ^k)
+    pub fn frequency(&self) -> f64 {
+        phi_power(self.index())
+    }
+
+    /// Get the period in seconds (1/φ^k)
+    pub fn period(&self) -> f64 {
+        1.0 / self.frequency()
+    }
+
+    /// Get the angular frequency in rad/sec (2π × φ^k)
+    pub fn omega(&self) -> f64 {
+        TAU * self.frequency()
+    }
+
+    /// Get the amplitude weight φ^(-|k|)
+    pub fn weight(&self) -> f64 {
+        phi_power(-self.index().abs())
+    }
+
+    /// Get the complete information for this band
+    pub fn info(&self) -> PhiBandInfo {
+        PhiBandInfo {
+            index: self.index(),
+            frequency: self.frequency(),
+            period: self.period(),
+            omega: self.omega(),
+            weight: self.weight(),
+            name: self.name(),
+            description: self.description(),
+        }
+    }
+
+    /// Check if a frequency falls within this band's range
+    pub fn contains(&self, freq_hz: f64) -> bool {
+        let (lower, upper) = band_frequency_range(*self);
+        lower <= freq_hz && freq_hz < upper
+    }
+}
+
+/// Get the frequency range for a φ band.
+///
+/// Uses geometric mean boundaries: (φ^(k-0.5), φ^(k+0.5))
+///
+/// # Arguments
+/// * `band` - The band to get the range for
+///
+/// # Returns
+/// (lower_hz, upper_hz) tuple
+pub fn band_frequency_range(band: PhiBand) -> (f64, f64) {
+    let centre = band.frequency();
+    let half_step = PHI.sqrt();
+    (centre / half_step, centre * half_step)
+}
+
+/// Classify a frequency into its φ band.
+///
+/// Frequencies below ULTRA or above RAPID are assigned to the nearest edge band.
+///
+/// # Arguments
+/// * `freq_hz` - Frequency in Hz
+///
+/// # Returns
+/// The PhiBand containing this frequency
+pub fn frequency_to_band(freq_hz: f64) -> PhiBand {
+    for band in PhiBand::ALL {
+        if band.contains(freq_hz) {
+            return band;
+        }
+    }
+
+    // Edge cases
+    if freq_hz < band_frequency_range(PhiBand::Ultra).0 {
+        PhiBand::Ultra
+    } else {
+        PhiBand::Rapid
+    }
+}
+
+/// Amplitude and phase measured in a single φ band
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BandMeasurement {
+    /// The band this measurement belongs to
+    pub band: PhiBand,
+    /// Band amplitude
+    pub amplitude: f64,
+    /// Band phase in radians
+    pub phase: f64,
+}
+
+impl BandMeasurement {
+    /// Create a new band measurement
+    pub const fn new(band: PhiBand, amplitude: f64, phase: f64) -> Self {
+        Self {
+            band,
+            amplitude,
+            phase,
+        }
+    }
+}
+
+/// Result of a multiwave coherence computation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MultiwaveCoherence {
+    /// Coherence clamped to the 0-1 range
+    pub coherence: f64,
+    /// Unclamped weighted sum of all band contributions
+    pub raw: f64,
+    /// Per-band contributions w_k × A_k × cos(ψ_k - ψ_ref), ordered ULTRA to RAPID
+    pub contributions: [f64; 5],
+}
+
+impl MultiwaveCoherence {
+    /// Get the contribution of a single band
+    pub fn contribution(&self, band: PhiBand) -> f64 {
+        self.contributions[band.position()]
+    }
+
+    /// Get the band with the lowest contribution
+    pub fn weakest_band(&self) -> PhiBand {
+        PhiBand::ALL
+            .into_iter()
+            .min_by(|a, b| self.contribution(*a).total_cmp(&self.contribution(*b)))
+            .unwrap_or(PhiBand::Core)
+    }
+}
+
+/// Compute weighted coherence from band amplitudes and phases.
+///
+/// Formula: C = Σ_k w_k × A_k × cos(ψ_k - ψ_ref)
+///
+/// Bands without a measurement contribute nothing. Multiple measurements
+/// for the same band are summed.
+///
+/// # Arguments
+/// * `measurements` - Amplitude/phase pairs per band
+/// * `reference_phase` - Reference phase for alignment (radians)
+///
+/// # Returns
+/// The clamped coherence together with the per-band contributions
+pub fn compute_multiwave_coherence(
+    measurements: &[BandMeasurement],
+    reference_phase: f64,
+) -> MultiwaveCoherence {
+    let mut contributions = [0.0; 5];
+
+    for m in measurements {
+        contributions[m.band.position()] +=
+            m.band.weight() * m.amplitude * (m.phase - reference_phase).cos();
+    }
+
+    let raw = contributions.iter().sum::<f64>();
+
+    MultiwaveCoherence {
+        coherence: raw.clamp(0.0, 1.0),
+        raw,
+        contributions,
+    }
+}
+
+/// Compute weighted coherence with the default reference phase of 0.
+pub fn compute_multiwave_coherence_default(measurements: &[BandMeasurement]) -> MultiwaveCoherence {
+    compute_multiwave_coherence(measurements, 0.0)
+}
+
+/// Result of a one-dimensional optimization
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OptimizeResult {
+    /// Location of the optimum
+    pub x: f64,
+    /// Function value at the optimum
+    pub value: f64,
+    /// Number of function evaluations
+    pub evaluations: usize,
+    /// True if the bracket shrank below the tolerance before the iteration cap
+    pub converged: bool,
+}
+
+fn check_bracket(lower: f64, upper: f64, tolerance: f64) {
+    if lower >= upper || lower.is_nan() || upper.is_nan() {
+        panic!("lower must be less than upper");
+    }
+    if tolerance <= 0.0 || tolerance.is_nan() {
+        panic!("tolerance must be positive");
+    }
+}
+
+/// Find the minimum of a unimodal function by golden-section search.
+///
+/// Each iteration shrinks the bracket by a factor of 1/φ and costs one evaluation.
+///
+/// # Arguments
+/// * `f` - Function to minimize
+/// * `lower` - Lower end of the bracket
+/// * `upper` - Upper end of the bracket
+/// * `tolerance` - Final bracket width
+/// * `max_iterations` - Iteration cap
+///
+/// # Returns
+/// The argmin, its value and the evaluation count
+///
+/// # Panics
+/// Panics if lower >= upper or tolerance <= 0
+pub fn golden_section_minimize<F: Fn(f64) -> f64>(
+    f: F,
+    lower: f64,
+    upper: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> OptimizeResult {
+    check_bracket(lower, upper, tolerance);
+
+    let (mut a, mut b) = (lower, upper);
+    let mut c = b - (b - a) * PHI_INVERSE;
+    let mut d = a + (b - a) * PHI_INVERSE;
+    let mut fc = f(c);
+    let mut fd = f(d);
+    let mut evaluations = 2;
+
+    for _ in 0..max_iterations {
+        if b - a <= tolerance {
+            break;
+        }
+
+        if fc < fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - (b - a) * PHI_INVERSE;
+            fc = f(c);
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + (b - a) * PHI_INVERSE;
+            fd = f(d);
+        }
+        evaluations += 1;
+    }
+
+    let (x, value) = if fc < fd { (c, fc) } else { (d, fd) };
+    OptimizeResult {
+        x,
+        value,
+        evaluations,
+        converged: b - a <= tolerance,
+    }
+}
+
+/// Find the maximum of a unimodal function by golden-section search.
+///
+/// See [`golden_section_minimize`] for the arguments.
+pub fn golden_section_maximize<F: Fn(f64) -> f64>(
+    f: F,
+    lower: f64,
+    upper: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> OptimizeResult {
+    let result = golden_section_minimize(|x| -f(x), lower, upper, tolerance, max_iterations);
+    OptimizeResult {
+        value: -result.value,
+        ..result
+    }
+}
+
+/// Find the minimum of a function with Brent's method.
+///
+/// Combines parabolic interpolation with golden-section steps, so smooth
+/// functions converge much faster while the worst case stays golden-section.
+///
+/// # Arguments
+/// * `f` - Function to minimize
+/// * `lower` - Lower end of the bracket
+/// * `upper` - Upper end of the bracket
+/// * `tolerance` - Final bracket width
+/// * `max_iterations` - Iteration cap
+///
+/// # Returns
+/// The argmin, its value and the evaluation count
+///
+/// # Panics
+/// Panics if lower >= upper or tolerance <= 0
+pub fn brent_minimize<F: Fn(f64) -> f64>(
+    f: F,
+    lower: f64,
+    upper: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> OptimizeResult {
+    check_bracket(lower, upper, tolerance);
+
+    // Golden-section step fraction 1 - 1/φ
+    let golden = 1.0 - PHI_INVERSE;
+    let (mut a, mut b) = (lower, upper);
+    let mut x = a + golden * (b - a);
+    let (mut w, mut v) = (x, x);
+    let mut fx = f(x);
+    let (mut fw, mut fv) = (fx, fx);
+    let (mut d, mut e) = (0.0_f64, 0.0_f64);
+    let mut evaluations = 1;
+
+    for _ in 0..max_iterations {
+        let midpoint = 0.5 * (a + b);
+        let tol1 = 0.5 * tolerance + f64::EPSILON * x.abs();
+        let tol2 = 2.0 * tol1;
+
+        if (x - midpoint).abs() <= tol2 - 0.5 * (b - a) {
+            return OptimizeResult {
+                x,
+                value: fx,
+                evaluations,
+                converged: true,
+            };
+        }
+
+        let mut use_golden = true;
+        if e.abs() > tol1 {
+            // Fit a parabola through x, w and v
+            let r = (x - w) * (fx - fv);
+            let mut q = (x - v) * (fx - fw);
+            let mut p = (x - v) * q - (x - w) * r;
+            q = 2.0 * (q - r);
+            if q > 0.0 {
+                p = -p;
+            }
+            q = q.abs();
+            let previous_step = e;
+            e = d;
+
+            if p.abs() < (0.5 * q * previous_step).abs() && p > q * (a - x) && p < q * (b - x) {
+                d = p / q;
+                let u = x + d;
+                if u - a < tol2 || b - u < tol2 {
+                    d = tol1.copysign(midpoint - x);
+                }
+                use_golden = false;
+            }
+        }
+
+        if use_golden {
+            e = if x >= midpoint { a - x } else { b - x };
+            d = golden * e;
+        }
+
+        let u = if d.abs() >= tol1 {
+            x + d
+        } else {
+            x + tol1.copysign(d)
+        };
+        let fu = f(u);
+        evaluations += 1;
+
+        if fu <= fx {
+            if u >= x {
+                a = x;
+            } else {
+                b = x;
+            }
+            (v, fv) = (w, fw);
+            (w, fw) = (x, fx);
+            (x, fx) = (u, fu);
+        } else {
+            if u < x {
+                a = u;
+            } else {
+                b = u;
+            }
+            if fu <= fw || w == x {
+                (v, fv) = (w, fw);
+                (w, fw) = (u, fu);
+            } else if fu <= fv || v == x || v == w {
+                (v, fv) = (u, fu);
+            }
+        }
+    }
+
+    OptimizeResult {
+        x,
+        value: fx,
+        evaluations,
+        converged: false,
+    }
+}
+
+/// Find the maximum of a function with Brent's method.
+///
+/// See [`brent_minimize`] for the arguments.
+pub fn brent_maximize<F: Fn(f64) -> f64>(
+    f: F,
+    lower: f64,
+    upper: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> OptimizeResult {
+    let result = brent_minimize(|x| -f(x), lower, upper, tolerance, max_iterations);
+    OptimizeResult {
+        value: -result.value,
+        ..result
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,6 +776,16 @@ mod tests {
         assert!((PHI_SQUARED - PHI - 1.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_named_phi_powers() {
+        assert!((ANKH - phi_power(3)).abs() < 1e-10);
+        assert!((RA - phi_power(5)).abs() < 1e-10);
+        assert!((SCARAB - phi_power(7)).abs() < 1e-10);
+        assert!((PHI_NEG2 - phi_power(-2)).abs() < 1e-10);
+        assert!((PHI_NEG3 - phi_power(-3)).abs() < 1e-10);
+        assert!((PHI_NEG4 - phi_power(-4)).abs() < 1e-10);
+    }
+
     #[test]
     fn test_phi_power() {
         assert!((phi_power(0) - 1.0).abs() < 1e-10);
@@ -120,10 +799,160 @@ mod tests {
         assert!((ratio - PHI).abs() < 1e-6);
     }
 
+    #[test]
+    fn test_fibonacci_and_lucas() {
+        assert_eq!(fibonacci(0), Ok(0));
+        assert_eq!(fibonacci(1), Ok(1));
+        assert_eq!(fibonacci(10), Ok(55));
+        assert_eq!(fibonacci(93), Ok(12200160415121876738));
+        assert_eq!(lucas(0), Ok(2));
+        assert_eq!(lucas(1), Ok(1));
+        assert_eq!(lucas(10), Ok(123));
+
+        let sequence: Vec<u128> = fibonacci_sequence().take(10).collect();
+        assert_eq!(sequence, [0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+        for (n, value) in fibonacci_sequence().enumerate().step_by(17) {
+            assert_eq!(fibonacci(n as u32), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_sequence_overflow() {
+        assert_eq!(
+            fibonacci_sequence().count(),
+            FIBONACCI_MAX_INDEX as usize + 1
+        );
+        assert_eq!(lucas_sequence().count(), LUCAS_MAX_INDEX as usize + 1);
+        assert!(fibonacci(FIBONACCI_MAX_INDEX).is_ok());
+        assert!(lucas(LUCAS_MAX_INDEX).is_ok());
+        assert_eq!(
+            fibonacci(FIBONACCI_MAX_INDEX + 1),
+            Err(SequenceOverflow {
+                sequence: "F",
+                index: FIBONACCI_MAX_INDEX + 1
+            })
+        );
+        assert!(lucas(LUCAS_MAX_INDEX + 1).is_err());
+    }
+
+    #[test]
+    fn test_fibonacci_ratio_large_n() {
+        assert_eq!(fibonacci_ratio(1), 1.0);
+        assert_eq!(fibonacci_ratio(2), 2.0);
+        for n in [92, 100, 185, 186, 1000, u32::MAX] {
+            assert!((fibonacci_ratio(n) - PHI).abs() < 1e-15);
+        }
+    }
+
     #[test]
     fn test_is_phi_ratio() {
         assert!(is_phi_ratio_default(1.0, PHI));
         assert!(is_phi_ratio_default(PHI, PHI_SQUARED));
         assert!(!is_phi_ratio_default(1.0, 2.0));
+        assert!(is_phi_ratio(1.0, 1.62, Tolerance::Relative(0.005)));
+        assert!(!is_phi_ratio(1.0, 1.62, Tolerance::Cents(1.0)));
+    }
+
+    #[test]
+    fn test_is_ratio() {
+        assert!(is_ratio(440.0, 660.0, 1.5, 1e-12));
+        assert!(is_ratio(660.0, 440.0, 1.5, Tolerance::Cents(0.01)));
+        assert!(is_ratio(432.0, 864.0, 2.0, Tolerance::Ulps(0)));
+        assert!(!is_ratio(0.0, 1.0, 1.0, Tolerance::Absolute(10.0)));
+    }
+
+    #[test]
+    fn test_phi_band_info() {
+        let fast = PhiBand::Fast.info();
+        assert_eq!(fast.index, 1);
+        assert!((fast.frequency - PHI).abs() < 1e-10);
+        assert!((fast.period - PHI_INVERSE).abs() < 1e-10);
+        assert!((fast.omega - TAU * PHI).abs() < 1e-10);
+        assert!((fast.weight - PHI_INVERSE).abs() < 1e-10);
+        assert_eq!(PhiBand::from_index(-2), Some(PhiBand::Ultra));
+        assert_eq!(PhiBand::from_index(3), None);
+    }
+
+    #[test]
+    fn test_band_frequency_range() {
+        let (lower, upper) = band_frequency_range(PhiBand::Core);
+        assert!((lower * upper - 1.0).abs() < 1e-10);
+        assert!((upper / lower - PHI).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_frequency_to_band() {
+        assert_eq!(frequency_to_band(1.0), PhiBand::Core);
+        assert_eq!(frequency_to_band(PHI), PhiBand::Fast);
+        assert_eq!(frequency_to_band(0.4), PhiBand::Ultra);
+        assert_eq!(frequency_to_band(0.01), PhiBand::Ultra);
+        assert_eq!(frequency_to_band(100.0), PhiBand::Rapid);
+    }
+
+    #[test]
+    fn test_compute_multiwave_coherence() {
+        let result = compute_multiwave_coherence_default(&[
+            BandMeasurement::new(PhiBand::Core, 0.5, 0.0),
+            BandMeasurement::new(PhiBand::Fast, 0.5, 0.0),
+        ]);
+        assert!((result.raw - (0.5 + 0.5 * PHI_INVERSE)).abs() < 1e-10);
+        assert!((result.coherence - result.raw).abs() < 1e-10);
+        assert_eq!(result.contribution(PhiBand::Ultra), 0.0);
+    }
+
+    #[test]
+    fn test_multiwave_coherence_phase_and_clamp() {
+        let result = compute_multiwave_coherence(
+            &[
+                BandMeasurement::new(PhiBand::Core, 2.0, PI / 2.0),
+                BandMeasurement::new(PhiBand::Slow, 1.0, PI),
+            ],
+            PI / 2.0,
+        );
+        assert!((result.contribution(PhiBand::Core) - 2.0).abs() < 1e-10);
+        assert!(result.contribution(PhiBand::Slow).abs() < 1e-10);
+        assert_eq!(result.coherence, 1.0);
+
+        let opposed = compute_multiwave_coherence_default(&[
+            BandMeasurement::new(PhiBand::Core, 0.2, 0.0),
+            BandMeasurement::new(PhiBand::Rapid, 1.0, PI),
+        ]);
+        assert_eq!(opposed.coherence, 0.0);
+        assert_eq!(opposed.weakest_band(), PhiBand::Rapid);
+    }
+
+    #[test]
+    fn test_golden_section_minimize() {
+        let result = golden_section_minimize(|x| (x - 2.0).powi(2), 0.0, 5.0, 1e-8, 200);
+        assert!(result.converged);
+        assert!((result.x - 2.0).abs() < 1e-6);
+        assert!(result.value < 1e-12);
+
+        let capped = golden_section_minimize(|x| (x - 2.0).powi(2), 0.0, 5.0, 1e-8, 5);
+        assert!(!capped.converged);
+        assert_eq!(capped.evaluations, 7);
+    }
+
+    #[test]
+    fn test_golden_section_maximize() {
+        // Peak response at SCHUMANN_FUNDAMENTAL-like 7.83 Hz
+        let response = |f: f64| (-(f - 7.83).powi(2)).exp();
+        let result = golden_section_maximize(response, 1.0, 20.0, 1e-8, 200);
+        assert!((result.x - 7.83).abs() < 1e-6);
+        assert!((result.value - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_brent() {
+        let f = |x: f64| x.cos() + 0.1 * x;
+        let golden = golden_section_minimize(f, 2.0, 5.0, 1e-9, 500);
+        let brent = brent_minimize(f, 2.0, 5.0, 1e-9, 500);
+        assert!(brent.converged);
+        assert!((brent.x - golden.x).abs() < 1e-6);
+        assert!(brent.evaluations < golden.evaluations);
+
+        let peak = brent_maximize(|x| -(x - 1.5).powi(2) + 3.0, -10.0, 10.0, 1e-10, 100);
+        assert!((peak.x - 1.5).abs() < 1e-8);
+        assert!((peak.value - 3.0).abs() < 1e-12);
     }
 }
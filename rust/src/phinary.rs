@@ -0,0 +1,339 @@
+//! Fibonacci-based encodings - Zeckendorf decomposition and base-φ (phinary) numerals.
+//!
+//! (c) 2025 Anywave Creations
+//! MIT License
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::phi::{fibonacci, fibonacci_sequence, phi_power, PHI};
+use crate::zphi::ZPhi;
+
+/// Error returned by the Zeckendorf and phinary encoders
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodingError {
+    /// Zeckendorf index below 2 (F(0) and F(1) are not used)
+    InvalidIndex(u32),
+    /// Zeckendorf indices that are equal or adjacent
+    ConsecutiveIndices(u32, u32),
+    /// Decoded value does not fit in the target type
+    Overflow,
+    /// Value is negative, NaN or infinite
+    InvalidValue,
+    /// Character other than '0', '1' or a single '.'
+    InvalidDigit(char),
+    /// Empty phinary string
+    Empty,
+}
+
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidIndex(k) => write!(f, "Zeckendorf index {} is below 2", k),
+            Self::ConsecutiveIndices(j, k) => {
+                write!(f, "Zeckendorf indices {} and {} are not separated", j, k)
+            }
+            Self::Overflow => write!(f, "value overflows the target type"),
+            Self::InvalidValue => write!(f, "value must be finite and non-negative"),
+            Self::InvalidDigit(c) => write!(f, "invalid phinary digit '{}'", c),
+            Self::Empty => write!(f, "empty phinary string"),
+        }
+    }
+}
+
+impl std::error::Error for EncodingError {}
+
+/// Decompose n into a sum of non-consecutive Fibonacci numbers.
+///
+/// # Arguments
+/// * `n` - The integer to decompose
+///
+/// # Returns
+/// Fibonacci indices in descending order, each >= 2 (F(2) = 1, F(3) = 2, ...).
+/// Zero decomposes into an empty list.
+pub fn zeckendorf(n: u128) -> Vec<u32> {
+    let fibs: Vec<u128> = fibonacci_sequence().take_while(|&f| f <= n).collect();
+    let mut indices = Vec::new();
+    let mut remainder = n;
+
+    // Greedy from the largest Fibonacci number; index 1 duplicates index 2
+    for k in (2..fibs.len()).rev() {
+        if fibs[k] <= remainder {
+            remainder -= fibs[k];
+            indices.push(k as u32);
+        }
+        if remainder == 0 {
+            break;
+        }
+    }
+
+    indices
+}
+
+/// Reconstruct an integer from its Zeckendorf indices.
+///
+/// # Arguments
+/// * `indices` - Fibonacci indices in any order
+///
+/// # Returns
+/// The sum of the Fibonacci numbers, or an error if the indices are not a
+/// valid Zeckendorf representation or the sum exceeds u128
+pub fn from_zeckendorf(indices: &[u32]) -> Result<u128, EncodingError> {
+    let mut sorted = indices.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+
+    for pair in sorted.windows(2) {
+        if pair[0] - pair[1] < 2 {
+            return Err(EncodingError::ConsecutiveIndices(pair[1], pair[0]));
+        }
+    }
+
+    sorted.iter().try_fold(0u128, |total, &k| {
+        if k < 2 {
+            return Err(EncodingError::InvalidIndex(k));
+        }
+        let f = fibonacci(k).map_err(|_| EncodingError::Overflow)?;
+        total.checked_add(f).ok_or(EncodingError::Overflow)
+    })
+}
+
+/// A base-φ numeral in standard form.
+///
+/// Stored as the exponents of its 1 digits in descending order; no two
+/// exponents are adjacent. Ordering follows the numeric value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Phinary {
+    exponents: Vec<i32>,
+}
+
+impl Phinary {
+    /// Build a numeral from arbitrary distinct exponents, normalizing to standard form
+    fn from_exponents(mut exponents: Vec<i32>) -> Self {
+        exponents.sort_unstable_by(|a, b| b.cmp(a));
+        exponents.dedup();
+
+        // Rewrite the highest adjacent pair φ^k + φ^(k-1) as φ^(k+1) until none remain
+        while let Some(i) = exponents.windows(2).position(|w| w[0] - w[1] == 1) {
+            let k = exponents[i];
+            exponents.splice(i..i + 2, [k + 1]);
+        }
+
+        Self { exponents }
+    }
+
+    /// Encode a non-negative integer exactly.
+    ///
+    /// Every integer has a finite standard base-φ representation.
+    pub fn from_integer(n: u64) -> Self {
+        let mut remainder = ZPhi::from(n as i128);
+        let mut exponents = Vec::new();
+
+        while remainder > ZPhi::ZERO {
+            let mut k = remainder.to_f64().log(PHI).floor() as i32;
+            // Correct the float estimate with exact comparisons
+            while ZPhi::phi_pow(k + 1).expect("u64 phinary exponent in range") <= remainder {
+                k += 1;
+            }
+            while ZPhi::phi_pow(k).expect("u64 phinary exponent in range") > remainder {
+                k -= 1;
+            }
+
+            remainder = remainder - ZPhi::phi_pow(k).expect("u64 phinary exponent in range");
+            exponents.push(k);
+        }
+
+        Self { exponents }
+    }
+
+    /// Encode a non-negative real, truncating after a number of fractional digits.
+    ///
+    /// # Arguments
+    /// * `value` - Value to encode
+    /// * `fractional_digits` - Maximum number of digits after the radix point;
+    ///   encoding stops early once the value is used up or places underflow f64
+    ///
+    /// # Returns
+    /// The standard-form numeral, or an error for negative or non-finite input
+    pub fn from_f64(value: f64, fractional_digits: u32) -> Result<Self, EncodingError> {
+        if !value.is_finite() || value < 0.0 {
+            return Err(EncodingError::InvalidValue);
+        }
+
+        let mut exponents = Vec::new();
+        let mut remainder = value;
+        // Beyond i32 there is nothing left to encode anyway, so clamp
+        let lowest = i32::try_from(fractional_digits).map_or(-i32::MAX, |digits| -digits);
+
+        if remainder > 0.0 {
+            let mut k = remainder.log(PHI).floor() as i32 + 1;
+            while k >= lowest && remainder > 0.0 {
+                let place = phi_power(k);
+                if place == 0.0 {
+                    break;
+                }
+                if place <= remainder {
+                    remainder -= place;
+                    exponents.push(k);
+                }
+                k -= 1;
+            }
+        }
+
+        // Rounding can leave an adjacent pair; normalize it away
+        Ok(Self::from_exponents(exponents))
+    }
+
+    /// Exponents of the 1 digits, highest first
+    pub fn exponents(&self) -> &[i32] {
+        &self.exponents
+    }
+
+    /// Check if this numeral represents zero
+    pub fn is_zero(&self) -> bool {
+        self.exponents.is_empty()
+    }
+
+    /// Exact value in `Z[φ]`, or None if a coefficient exceeds i128
+    pub fn to_zphi(&self) -> Option<ZPhi> {
+        self.exponents
+            .iter()
+            .try_fold(ZPhi::ZERO, |total, &k| total.checked_add(ZPhi::phi_pow(k)?))
+    }
+
+    /// Decode to an integer if the numeral represents one exactly
+    pub fn to_integer(&self) -> Option<u128> {
+        let value = self.to_zphi()?;
+        if value.b == 0 && value.a >= 0 {
+            Some(value.a as u128)
+        } else {
+            None
+        }
+    }
+
+    /// Decode to the nearest f64
+    pub fn to_f64(&self) -> f64 {
+        match self.to_zphi() {
+            Some(value) => value.to_f64(),
+            None => self.exponents.iter().map(|&k| phi_power(k)).sum(),
+        }
+    }
+}
+
+impl fmt::Display for Phinary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let highest = self.exponents.first().copied().unwrap_or(0).max(0);
+        let lowest = self.exponents.last().copied().unwrap_or(0).min(0);
+
+        for k in (lowest..=highest).rev() {
+            if k == -1 {
+                write!(f, ".")?;
+            }
+            let digit = if self.exponents.contains(&k) {
+                '1'
+            } else {
+                '0'
+            };
+            write!(f, "{}", digit)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Phinary {
+    type Err = EncodingError;
+
+    /// Parse a numeral such as "100.01", normalizing it to standard form
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(EncodingError::Empty);
+        }
+
+        let (integer, fraction) = s.split_once('.').unwrap_or((s, ""));
+        let mut exponents = Vec::new();
+
+        for (i, c) in integer.chars().rev().enumerate() {
+            match c {
+                '0' => {}
+                '1' => exponents.push(i as i32),
+                _ => return Err(EncodingError::InvalidDigit(c)),
+            }
+        }
+        for (i, c) in fraction.chars().enumerate() {
+            match c {
+                '0' => {}
+                '1' => exponents.push(-(i as i32) - 1),
+                _ => return Err(EncodingError::InvalidDigit(c)),
+            }
+        }
+
+        Ok(Self::from_exponents(exponents))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zeckendorf_roundtrip() {
+        assert_eq!(zeckendorf(0), Vec::<u32>::new());
+        assert_eq!(zeckendorf(1), vec![2]);
+        assert_eq!(zeckendorf(100), vec![11, 6, 4]); // 89 + 8 + 3
+        for n in (0..2000).chain([u64::MAX as u128, u128::MAX]) {
+            let indices = zeckendorf(n);
+            assert!(indices.windows(2).all(|w| w[0] - w[1] >= 2));
+            assert_eq!(from_zeckendorf(&indices), Ok(n));
+        }
+    }
+
+    #[test]
+    fn test_from_zeckendorf_errors() {
+        assert_eq!(
+            from_zeckendorf(&[5, 4]),
+            Err(EncodingError::ConsecutiveIndices(4, 5))
+        );
+        assert_eq!(from_zeckendorf(&[1]), Err(EncodingError::InvalidIndex(1)));
+        assert_eq!(from_zeckendorf(&[186, 184]), Err(EncodingError::Overflow));
+    }
+
+    #[test]
+    fn test_phinary_integers() {
+        assert_eq!(Phinary::from_integer(0).to_string(), "0");
+        assert_eq!(Phinary::from_integer(1).to_string(), "1");
+        assert_eq!(Phinary::from_integer(2).to_string(), "10.01");
+        assert_eq!(Phinary::from_integer(3).to_string(), "100.01");
+        assert_eq!(Phinary::from_integer(4).to_string(), "101.01");
+        for n in (0..500).chain([u32::MAX as u64, u64::MAX]) {
+            let numeral = Phinary::from_integer(n);
+            assert!(numeral.exponents().windows(2).all(|w| w[0] - w[1] >= 2));
+            assert_eq!(numeral.to_integer(), Some(n as u128));
+        }
+        assert!(Phinary::from_integer(7) < Phinary::from_integer(8));
+    }
+
+    #[test]
+    fn test_phinary_parse_and_reals() {
+        let parsed: Phinary = "11".parse().unwrap();
+        assert_eq!(parsed.to_string(), "100");
+        assert_eq!("0.11".parse::<Phinary>().unwrap().to_string(), "1");
+        assert_eq!(
+            "10.2".parse::<Phinary>(),
+            Err(EncodingError::InvalidDigit('2'))
+        );
+
+        let encoded = Phinary::from_f64(PHI, 20).unwrap();
+        assert_eq!(encoded.to_string(), "10");
+        let approx = Phinary::from_f64(std::f64::consts::PI, 40).unwrap();
+        assert!((approx.to_f64() - std::f64::consts::PI).abs() < 1e-8);
+        assert_eq!(
+            Phinary::from_f64(0.5, u32::MAX),
+            Phinary::from_f64(0.5, 2000)
+        );
+        assert!(!Phinary::from_f64(0.5, u32::MAX).unwrap().is_zero());
+        assert_eq!(
+            Phinary::from_f64(-1.0, 10),
+            Err(EncodingError::InvalidValue)
+        );
+    }
+}
@@ -0,0 +1,351 @@
+//! Known-frequency registry - every named crate frequency with nearest-match search.
+//!
+//! (c) 2025 Anywave Creations
+//! MIT License
+
+use std::ops::RangeInclusive;
+use std::sync::OnceLock;
+
+use crate::frequencies::{
+    cents_difference, octave_of, MaterialFrequency, A432, A440, SCHUMANN_2ND, SCHUMANN_3RD,
+    SCHUMANN_4TH, SCHUMANN_5TH, SCHUMANN_FUNDAMENTAL, SOLFEGGIO_FA, SOLFEGGIO_LA, SOLFEGGIO_MI,
+    SOLFEGGIO_RE, SOLFEGGIO_SOL, SOLFEGGIO_UT,
+};
+use crate::phi::PhiBand;
+use crate::tolerance::Tolerance;
+
+/// Largest octave shift considered for octave-equivalent matches
+pub const MAX_OCTAVE_SHIFT: i32 = 16;
+
+/// Kind of named frequency
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum FrequencyCategory {
+    Schumann,
+    Solfeggio,
+    PitchStandard,
+    Material,
+    PhiBand,
+}
+
+impl FrequencyCategory {
+    /// Get the human-readable name
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Schumann => "Schumann resonance",
+            Self::Solfeggio => "Solfeggio",
+            Self::PitchStandard => "Pitch standard",
+            Self::Material => "Material",
+            Self::PhiBand => "φ-band",
+        }
+    }
+}
+
+/// A named frequency from the crate's constants
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KnownFrequency {
+    /// Unique name, e.g. "Schumann 1", "A432" or "Quartz"
+    pub name: &'static str,
+    /// Frequency in Hz
+    pub frequency: f64,
+    /// Kind of frequency
+    pub category: FrequencyCategory,
+    /// Short description of the frequency's role
+    pub description: &'static str,
+}
+
+impl KnownFrequency {
+    const fn new(
+        name: &'static str,
+        frequency: f64,
+        category: FrequencyCategory,
+        description: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            frequency,
+            category,
+            description,
+        }
+    }
+}
+
+/// A known frequency matched to a measured frequency or range
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrequencyMatch {
+    /// The registry entry that matched
+    pub known: &'static KnownFrequency,
+    /// Octave shift applied to the known frequency (0 = direct match)
+    pub octaves: i32,
+    /// The known frequency after the octave shift, in Hz
+    pub frequency: f64,
+    /// Distance in cents from the shifted known frequency to the query
+    pub cents: f64,
+}
+
+/// Every named frequency in the crate: Schumann harmonics, solfeggio tones,
+/// pitch standards, material frequencies and φ-band centres
+pub fn known_frequencies() -> &'static [KnownFrequency] {
+    static REGISTRY: OnceLock<Vec<KnownFrequency>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        use FrequencyCategory::{Material, PitchStandard, Schumann, Solfeggio};
+
+        let mut known = vec![
+            KnownFrequency::new(
+                "Schumann 1",
+                SCHUMANN_FUNDAMENTAL,
+                Schumann,
+                "Schumann resonance fundamental",
+            ),
+            KnownFrequency::new(
+                "Schumann 2",
+                SCHUMANN_2ND,
+                Schumann,
+                "Second Schumann harmonic",
+            ),
+            KnownFrequency::new(
+                "Schumann 3",
+                SCHUMANN_3RD,
+                Schumann,
+                "Third Schumann harmonic",
+            ),
+            KnownFrequency::new(
+                "Schumann 4",
+                SCHUMANN_4TH,
+                Schumann,
+                "Fourth Schumann harmonic",
+            ),
+            KnownFrequency::new(
+                "Schumann 5",
+                SCHUMANN_5TH,
+                Schumann,
+                "Fifth Schumann harmonic",
+            ),
+            KnownFrequency::new(
+                "Solfeggio UT",
+                SOLFEGGIO_UT,
+                Solfeggio,
+                "Liberation from fear and guilt",
+            ),
+            KnownFrequency::new(
+                "Solfeggio RE",
+                SOLFEGGIO_RE,
+                Solfeggio,
+                "Facilitating change, undoing situations",
+            ),
+            KnownFrequency::new(
+                "Solfeggio MI",
+                SOLFEGGIO_MI,
+                Solfeggio,
+                "Transformation, miracles, DNA repair",
+            ),
+            KnownFrequency::new(
+                "Solfeggio FA",
+                SOLFEGGIO_FA,
+                Solfeggio,
+                "Connecting relationships, harmony",
+            ),
+            KnownFrequency::new(
+                "Solfeggio SOL",
+                SOLFEGGIO_SOL,
+                Solfeggio,
+                "Awakening intuition, expression",
+            ),
+            KnownFrequency::new(
+                "Solfeggio LA",
+                SOLFEGGIO_LA,
+                Solfeggio,
+                "Returning to spiritual order",
+            ),
+            KnownFrequency::new("A432", A432, PitchStandard, "Verdi tuning, A4 = 432 Hz"),
+            KnownFrequency::new("A440", A440, PitchStandard, "Concert pitch, A4 = 440 Hz"),
+        ];
+
+        for material in MaterialFrequency::ALL {
+            known.push(KnownFrequency::new(
+                material.name(),
+                material.frequency(),
+                Material,
+                "Material base resonance frequency",
+            ));
+        }
+        for band in PhiBand::ALL {
+            known.push(KnownFrequency::new(
+                band.name(),
+                band.frequency(),
+                FrequencyCategory::PhiBand,
+                band.description(),
+            ));
+        }
+
+        known
+    })
+}
+
+/// Look up a known frequency by name, ignoring case
+pub fn known_frequency(name: &str) -> Option<&'static KnownFrequency> {
+    known_frequencies()
+        .iter()
+        .find(|known| known.name.eq_ignore_ascii_case(name))
+}
+
+/// Find known frequencies matching a measured frequency, directly or at an octave.
+///
+/// This is the crate's single nearest-known-frequency search; spectral peaks
+/// are annotated with its best match.
+///
+/// Each known frequency is shifted to the octave nearest the measurement
+/// (at most `MAX_OCTAVE_SHIFT` octaves) and kept if it lies within tolerance.
+///
+/// # Arguments
+/// * `frequency` - Measured frequency in Hz
+/// * `tolerance` - Acceptable deviation from the shifted known frequency
+///
+/// # Returns
+/// Matches ranked by cents distance, direct matches first on ties
+pub fn nearest_known(frequency: f64, tolerance: impl Into<Tolerance>) -> Vec<FrequencyMatch> {
+    if frequency.is_nan() || frequency <= 0.0 {
+        return Vec::new();
+    }
+
+    let tolerance = tolerance.into();
+    let mut matches: Vec<FrequencyMatch> = known_frequencies()
+        .iter()
+        .filter_map(|known| {
+            let octaves = (frequency / known.frequency).log2().round() as i32;
+            if octaves.abs() > MAX_OCTAVE_SHIFT {
+                return None;
+            }
+            let shifted = octave_of(known.frequency, octaves);
+            tolerance
+                .matches(frequency, shifted)
+                .then(|| FrequencyMatch {
+                    known,
+                    octaves,
+                    frequency: shifted,
+                    cents: cents_difference(shifted, frequency),
+                })
+        })
+        .collect();
+
+    matches.sort_by(|a, b| {
+        a.cents
+            .abs()
+            .total_cmp(&b.cents.abs())
+            .then(a.octaves.abs().cmp(&b.octaves.abs()))
+    });
+    matches
+}
+
+/// Find known frequencies, and their octave equivalents, inside a frequency range.
+///
+/// # Arguments
+/// * `range` - Frequency range in Hz
+///
+/// # Returns
+/// Matches ranked with direct matches first, then by octave shift, then by
+/// cents distance from the range's geometric centre (`cents` is measured
+/// from the shifted frequency to that centre)
+pub fn known_within(range: RangeInclusive<f64>) -> Vec<FrequencyMatch> {
+    let (low, high) = (*range.start(), *range.end());
+    if low.is_nan() || high.is_nan() || high <= 0.0 || low > high {
+        return Vec::new();
+    }
+    let low = low.max(f64::MIN_POSITIVE);
+    let centre = (low * high).sqrt();
+
+    let mut matches = Vec::new();
+    for known in known_frequencies() {
+        let first = (low / known.frequency)
+            .log2()
+            .ceil()
+            .max(-MAX_OCTAVE_SHIFT as f64) as i32;
+        let last = (high / known.frequency)
+            .log2()
+            .floor()
+            .min(MAX_OCTAVE_SHIFT as f64) as i32;
+        for octaves in first..=last {
+            let shifted = octave_of(known.frequency, octaves);
+            if range.contains(&shifted) {
+                matches.push(FrequencyMatch {
+                    known,
+                    octaves,
+                    frequency: shifted,
+                    cents: cents_difference(shifted, centre),
+                });
+            }
+        }
+    }
+
+    matches.sort_by(|a, b| {
+        a.octaves
+            .abs()
+            .cmp(&b.octaves.abs())
+            .then(a.cents.abs().total_cmp(&b.cents.abs()))
+    });
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry() {
+        let known = known_frequencies();
+        assert_eq!(known.len(), 5 + 6 + 2 + 8 + 5);
+        assert_eq!(known[0].frequency, SCHUMANN_FUNDAMENTAL);
+
+        let gold = known_frequency("gold").unwrap();
+        assert_eq!(gold.frequency, 24576.0);
+        assert_eq!(gold.category, FrequencyCategory::Material);
+        assert_eq!(known_frequency("CORE").unwrap().frequency, 1.0);
+        assert!(known_frequency("Unobtainium").is_none());
+    }
+
+    #[test]
+    fn test_nearest_known() {
+        let matches = nearest_known(528.5, Tolerance::Cents(5.0));
+        assert_eq!(matches[0].known.name, "Solfeggio MI");
+        assert_eq!(matches[0].octaves, 0);
+        assert!((matches[0].cents - cents_difference(528.0, 528.5)).abs() < 1e-12);
+
+        // 864 Hz is A432 an octave up; 32768 Hz is quartz directly and copper an octave up
+        let octave = nearest_known(864.0, Tolerance::Cents(1.0));
+        assert_eq!(octave[0].known.name, "A432");
+        assert_eq!(octave[0].octaves, 1);
+        let quartz = nearest_known(32768.0, 1e-9);
+        assert_eq!(quartz[0].known.name, "Quartz");
+        assert!(quartz
+            .iter()
+            .any(|m| m.known.name == "Copper" && m.octaves == 1));
+
+        assert!(nearest_known(500.0, Tolerance::Cents(1.0)).is_empty());
+        assert!(nearest_known(-1.0, Tolerance::Cents(100.0)).is_empty());
+    }
+
+    #[test]
+    fn test_known_within() {
+        let matches = known_within(400.0..=450.0);
+        let direct: Vec<&str> = matches
+            .iter()
+            .take_while(|m| m.octaves == 0)
+            .map(|m| m.known.name)
+            .collect();
+        assert_eq!(direct, ["Solfeggio RE", "A432", "A440"]);
+        assert!(matches
+            .iter()
+            .any(|m| m.known.name == "Solfeggio LA" && m.octaves == -1));
+        assert!(matches
+            .iter()
+            .all(|m| (400.0..=450.0).contains(&m.frequency)));
+
+        let schumann = known_within(7.0..=8.0);
+        assert_eq!(schumann[0].known.name, "Schumann 1");
+        assert!(known_within(10.0..=5.0).is_empty());
+    }
+}
@@ -0,0 +1,747 @@
+//! Scala tuning files - .scl scales and .kbm keyboard mappings.
+//!
+//! (c) 2025 Anywave Creations
+//! MIT License
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::continued_fraction::Fraction;
+use crate::frequencies::SOLFEGGIO_FREQUENCIES;
+use crate::phi::PHI;
+use crate::tuning::{ScaleTuning, Tuning};
+
+/// What went wrong while reading a Scala file
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScalaErrorKind {
+    /// File ended before the description line
+    MissingDescription,
+    /// Note count or header field missing
+    MissingField(&'static str),
+    /// Header field is not a valid number
+    InvalidField(&'static str),
+    /// Pitch is neither cents nor a positive ratio
+    InvalidPitch(String),
+    /// Fewer pitch lines than the note count
+    CountMismatch { expected: usize, found: usize },
+    /// Fewer mapping entries than the map size
+    MissingMappingEntries { expected: usize, found: usize },
+    /// Mapping entry is neither a degree (up to i32::MAX) nor 'x'
+    InvalidMapping(String),
+    /// Pitches do not ascend, so they cannot form a `ScaleTuning`
+    UnsortedPitches,
+}
+
+/// Error produced while reading or converting a Scala file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScalaError {
+    /// 1-based line number (one past the last line for unexpected end of file),
+    /// or None for errors not tied to a file position
+    pub line: Option<usize>,
+    /// What went wrong
+    pub kind: ScalaErrorKind,
+}
+
+impl ScalaError {
+    const fn new(line: usize, kind: ScalaErrorKind) -> Self {
+        Self {
+            line: Some(line),
+            kind,
+        }
+    }
+
+    const fn unlocated(kind: ScalaErrorKind) -> Self {
+        Self { line: None, kind }
+    }
+}
+
+impl fmt::Display for ScalaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {}: ", line)?;
+        }
+        match &self.kind {
+            ScalaErrorKind::MissingDescription => write!(f, "missing description line"),
+            ScalaErrorKind::MissingField(name) => write!(f, "missing {}", name),
+            ScalaErrorKind::InvalidField(name) => write!(f, "invalid {}", name),
+            ScalaErrorKind::InvalidPitch(text) => write!(f, "invalid pitch '{}'", text),
+            ScalaErrorKind::CountMismatch { expected, found } => {
+                write!(f, "expected {} pitches, found {}", expected, found)
+            }
+            ScalaErrorKind::MissingMappingEntries { expected, found } => {
+                write!(f, "expected {} mapping entries, found {}", expected, found)
+            }
+            ScalaErrorKind::InvalidMapping(text) => write!(f, "invalid mapping entry '{}'", text),
+            ScalaErrorKind::UnsortedPitches => write!(f, "pitches must ascend"),
+        }
+    }
+}
+
+impl std::error::Error for ScalaError {}
+
+/// Iterate over (line number, line) pairs, skipping '!' comments
+fn content_lines(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim_end_matches('\r')))
+        .filter(|(_, line)| !line.starts_with('!'))
+}
+
+fn first_token(line: &str) -> &str {
+    line.split_whitespace().next().unwrap_or("")
+}
+
+/// A single scale pitch, as written in a .scl file
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScalaPitch {
+    /// Pitch in cents above the tonic
+    Cents(f64),
+    /// Frequency ratio numerator/denominator
+    Ratio(u64, u64),
+}
+
+impl ScalaPitch {
+    /// Ratio in lowest terms
+    pub fn ratio(numerator: u64, denominator: u64) -> Self {
+        let reduced = Fraction::new(numerator, denominator).reduced();
+        Self::Ratio(reduced.numerator, reduced.denominator)
+    }
+
+    /// Frequency ratio to the tonic
+    pub fn to_ratio(&self) -> f64 {
+        match *self {
+            Self::Cents(cents) => 2.0_f64.powf(cents / 1200.0),
+            Self::Ratio(n, d) => n as f64 / d as f64,
+        }
+    }
+
+    /// Size in cents above the tonic
+    pub fn to_cents(&self) -> f64 {
+        1200.0 * self.to_ratio().log2()
+    }
+}
+
+impl fmt::Display for ScalaPitch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            // The decimal point is what marks a value as cents
+            Self::Cents(cents) => write!(f, "{:.6}", cents),
+            Self::Ratio(n, d) => write!(f, "{}/{}", n, d),
+        }
+    }
+}
+
+impl FromStr for ScalaPitch {
+    type Err = ScalaErrorKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ScalaErrorKind::InvalidPitch(s.to_string());
+
+        if s.contains('.') {
+            let cents: f64 = s.parse().map_err(|_| invalid())?;
+            return if cents.is_finite() {
+                Ok(Self::Cents(cents))
+            } else {
+                Err(invalid())
+            };
+        }
+
+        let (n, d) = s.split_once('/').unwrap_or((s, "1"));
+        let n: u64 = n.parse().map_err(|_| invalid())?;
+        let d: u64 = d.parse().map_err(|_| invalid())?;
+        if n == 0 || d == 0 {
+            return Err(invalid());
+        }
+        Ok(Self::Ratio(n, d))
+    }
+}
+
+/// A scale read from or written to a .scl file.
+///
+/// The tonic 1/1 is implicit; the last pitch is the period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScalaScale {
+    /// One-line description of the scale
+    pub description: String,
+    /// Pitches above the tonic in ascending order, ending with the period
+    pub pitches: Vec<ScalaPitch>,
+}
+
+impl ScalaScale {
+    /// Create a scale from its description and pitches (period last)
+    pub fn new(description: impl Into<String>, pitches: Vec<ScalaPitch>) -> Self {
+        Self {
+            description: description.into(),
+            pitches,
+        }
+    }
+
+    /// Build an octave-repeating scale from frequencies.
+    ///
+    /// Frequencies are folded into the octave above the first one and sorted.
+    /// Whole-number frequencies give exact ratios, others give cents.
+    pub fn from_frequencies(description: impl Into<String>, frequencies: &[f64]) -> Self {
+        let Some(&tonic) = frequencies.first() else {
+            return Self::new(description, vec![ScalaPitch::Ratio(2, 1)]);
+        };
+        let exact = frequencies
+            .iter()
+            .all(|f| f.fract() == 0.0 && *f > 0.0 && *f < u32::MAX as f64);
+
+        let mut pitches: Vec<ScalaPitch> = frequencies
+            .iter()
+            .map(|&f| {
+                let octaves = (f / tonic).log2().floor() as i32;
+                if exact {
+                    let (mut n, mut d) = (f as u64, tonic as u64);
+                    if octaves >= 0 {
+                        d <<= octaves;
+                    } else {
+                        n <<= -octaves;
+                    }
+                    ScalaPitch::ratio(n, d)
+                } else {
+                    ScalaPitch::Cents(1200.0 * (f / tonic).log2() - 1200.0 * octaves as f64)
+                }
+            })
+            .filter(|p| p.to_ratio() > 1.0)
+            .collect();
+
+        pitches.sort_by(|a, b| a.to_ratio().total_cmp(&b.to_ratio()));
+        pitches.dedup_by(|a, b| (a.to_cents() - b.to_cents()).abs() < 1e-9);
+        pitches.push(ScalaPitch::Ratio(2, 1));
+        Self::new(description, pitches)
+    }
+
+    /// The six solfeggio tones folded into one octave above UT (396 Hz)
+    pub fn solfeggio() -> Self {
+        Self::from_frequencies("Solfeggio frequencies", &SOLFEGGIO_FREQUENCIES)
+    }
+
+    /// A φ-ladder: the interval φ split into equal steps, repeating at φ
+    ///
+    /// # Panics
+    /// Panics if divisions is 0
+    pub fn phi_ladder(divisions: u32) -> Self {
+        if divisions == 0 {
+            panic!("divisions must be >= 1");
+        }
+
+        let phi_cents = 1200.0 * PHI.log2();
+        let pitches = (1..=divisions)
+            .map(|k| ScalaPitch::Cents(phi_cents * k as f64 / divisions as f64))
+            .collect();
+        Self::new(
+            format!(
+                "Phi ladder, {} equal divisions of the golden ratio",
+                divisions
+            ),
+            pitches,
+        )
+    }
+
+    /// 5-limit just intonation chromatic scale with exact ratios
+    pub fn just_intonation() -> Self {
+        let ratios = [
+            (16, 15),
+            (9, 8),
+            (6, 5),
+            (5, 4),
+            (4, 3),
+            (45, 32),
+            (3, 2),
+            (8, 5),
+            (5, 3),
+            (9, 5),
+            (15, 8),
+            (2, 1),
+        ];
+        Self::new(
+            "5-limit just intonation",
+            ratios
+                .iter()
+                .map(|&(n, d)| ScalaPitch::Ratio(n, d))
+                .collect(),
+        )
+    }
+
+    /// Export a ratio-based tuning as cents
+    pub fn from_tuning(description: impl Into<String>, tuning: &ScaleTuning) -> Self {
+        let mut pitches: Vec<ScalaPitch> = tuning.ratios()[1..]
+            .iter()
+            .map(|r| ScalaPitch::Cents(1200.0 * r.log2()))
+            .collect();
+        pitches.push(ScalaPitch::Cents(1200.0 * tuning.period().log2()));
+        Self::new(description, pitches)
+    }
+
+    /// Period as a frequency ratio (2.0 for octave-repeating scales)
+    pub fn period(&self) -> f64 {
+        self.pitches.last().map_or(2.0, ScalaPitch::to_ratio)
+    }
+
+    /// Frequency ratio of a scale degree to the tonic; degrees repeat every period
+    pub fn ratio(&self, degree: i32) -> f64 {
+        let size = self.pitches.len().max(1) as i32;
+        let step = degree.rem_euclid(size);
+        let base = if step == 0 {
+            1.0
+        } else {
+            self.pitches[step as usize - 1].to_ratio()
+        };
+        base * self.period().powi(degree.div_euclid(size))
+    }
+
+    /// Convert to a `ScaleTuning` on a tonic frequency.
+    ///
+    /// # Returns
+    /// The tuning, or an error if the pitches do not ascend
+    pub fn to_tuning(&self, tonic: f64) -> Result<ScaleTuning, ScalaError> {
+        let ratios: Vec<f64> = self.pitches.iter().map(ScalaPitch::to_ratio).collect();
+        let ascending = std::iter::once(1.0)
+            .chain(ratios.iter().copied())
+            .collect::<Vec<_>>()
+            .windows(2)
+            .all(|w| w[0] < w[1]);
+        if !ascending || ratios.is_empty() {
+            return Err(ScalaError::unlocated(ScalaErrorKind::UnsortedPitches));
+        }
+
+        Ok(ScaleTuning::new(
+            tonic,
+            &ratios[..ratios.len() - 1],
+            self.period(),
+        ))
+    }
+}
+
+impl fmt::Display for ScalaScale {
+    /// Writes the scale in .scl format
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "! Generated by ra-constants")?;
+        writeln!(f, "!")?;
+        writeln!(f, "{}", self.description)?;
+        writeln!(f, " {}", self.pitches.len())?;
+        writeln!(f, "!")?;
+        for pitch in &self.pitches {
+            writeln!(f, " {}", pitch)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ScalaScale {
+    type Err = ScalaError;
+
+    /// Parse the contents of a .scl file
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let end = s.lines().count() + 1;
+        let mut lines = content_lines(s);
+
+        let (_, description) = lines
+            .next()
+            .ok_or(ScalaError::new(end, ScalaErrorKind::MissingDescription))?;
+
+        let (count_line, count) = lines.next().ok_or(ScalaError::new(
+            end,
+            ScalaErrorKind::MissingField("note count"),
+        ))?;
+        let count: usize = first_token(count)
+            .parse()
+            .map_err(|_| ScalaError::new(count_line, ScalaErrorKind::InvalidField("note count")))?;
+
+        // The count comes from the file, so let the lines bound the allocation
+        let pitches = lines
+            .take(count)
+            .map(|(line, text)| {
+                first_token(text)
+                    .parse()
+                    .map_err(|kind| ScalaError::new(line, kind))
+            })
+            .collect::<Result<Vec<ScalaPitch>, _>>()?;
+
+        if pitches.len() < count {
+            return Err(ScalaError::new(
+                end,
+                ScalaErrorKind::CountMismatch {
+                    expected: count,
+                    found: pitches.len(),
+                },
+            ));
+        }
+
+        Ok(Self::new(description.trim(), pitches))
+    }
+}
+
+/// Largest octave degree or mapping entry accepted from a .kbm file
+const MAX_DEGREE: u32 = i32::MAX as u32;
+
+/// A keyboard mapping read from or written to a .kbm file
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyboardMapping {
+    /// First MIDI note to retune
+    pub first_note: u8,
+    /// Last MIDI note to retune
+    pub last_note: u8,
+    /// Note where the mapping pattern starts (mapped to degree 0)
+    pub middle_note: u8,
+    /// Note whose frequency is given
+    pub reference_note: u8,
+    /// Frequency of the reference note in Hz
+    pub reference_frequency: f64,
+    /// Scale degree treated as the formal octave (0 = scale size)
+    pub octave_degree: u32,
+    /// Scale degree per key in the pattern, None for unmapped keys; empty = linear
+    pub mapping: Vec<Option<u32>>,
+}
+
+impl KeyboardMapping {
+    /// Linear mapping of all 128 keys with degree 0 on the middle note
+    pub fn linear(middle_note: u8, reference_note: u8, reference_frequency: f64) -> Self {
+        Self {
+            first_note: 0,
+            last_note: 127,
+            middle_note,
+            reference_note,
+            reference_frequency,
+            octave_degree: 0,
+            mapping: Vec::new(),
+        }
+    }
+
+    /// Scale degree of a key, or None if unmapped or beyond the i32 range
+    fn degree(&self, note: u8, scale_size: usize) -> Option<i32> {
+        let offset = note as i32 - self.middle_note as i32;
+        if self.mapping.is_empty() {
+            return Some(offset);
+        }
+
+        let size = i32::try_from(self.mapping.len()).ok()?;
+        let octave_degree = match self.octave_degree {
+            0 => i32::try_from(scale_size).ok()?,
+            n => i32::try_from(n).ok()?,
+        };
+        let mapped = i32::try_from(self.mapping[offset.rem_euclid(size) as usize]?).ok()?;
+        offset
+            .div_euclid(size)
+            .checked_mul(octave_degree)?
+            .checked_add(mapped)
+    }
+
+    /// Frequency of a MIDI note under a scale.
+    ///
+    /// # Returns
+    /// Frequency in Hz, or None if the note is outside the retuned range, unmapped,
+    /// or maps to a degree beyond the i32 range
+    pub fn note_frequency(&self, scale: &ScalaScale, note: u8) -> Option<f64> {
+        if note < self.first_note || note > self.last_note {
+            return None;
+        }
+
+        let size = scale.pitches.len();
+        let degree = self.degree(note, size)?;
+        let reference = self.degree(self.reference_note, size)?;
+        Some(self.reference_frequency * scale.ratio(degree) / scale.ratio(reference))
+    }
+}
+
+impl fmt::Display for KeyboardMapping {
+    /// Writes the mapping in .kbm format
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "! Generated by ra-constants")?;
+        writeln!(f, "! Map size")?;
+        writeln!(f, "{}", self.mapping.len())?;
+        writeln!(f, "! First and last MIDI notes to retune")?;
+        writeln!(f, "{}", self.first_note)?;
+        writeln!(f, "{}", self.last_note)?;
+        writeln!(
+            f,
+            "! Middle note where the first entry of the mapping is mapped to"
+        )?;
+        writeln!(f, "{}", self.middle_note)?;
+        writeln!(f, "! Reference note and frequency")?;
+        writeln!(f, "{}", self.reference_note)?;
+        writeln!(f, "{:.6}", self.reference_frequency)?;
+        writeln!(f, "! Scale degree to consider as formal octave")?;
+        writeln!(f, "{}", self.octave_degree)?;
+        writeln!(f, "! Mapping")?;
+        for entry in &self.mapping {
+            match entry {
+                Some(degree) => writeln!(f, "{}", degree)?,
+                None => writeln!(f, "x")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for KeyboardMapping {
+    type Err = ScalaError;
+
+    /// Parse the contents of a .kbm file.
+    ///
+    /// The file must contain as many mapping entries as its map size, and the
+    /// octave degree and mapping entries must fit in an i32.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let end = s.lines().count() + 1;
+        let mut lines = content_lines(s).filter(|(_, line)| !line.trim().is_empty());
+
+        let mut field = |name: &'static str| {
+            let (line, text) = lines
+                .next()
+                .ok_or(ScalaError::new(end, ScalaErrorKind::MissingField(name)))?;
+            Ok::<_, ScalaError>((line, first_token(text)))
+        };
+
+        fn number<T: FromStr>(
+            (line, text): (usize, &str),
+            name: &'static str,
+        ) -> Result<T, ScalaError> {
+            text.parse()
+                .map_err(|_| ScalaError::new(line, ScalaErrorKind::InvalidField(name)))
+        }
+
+        let size: usize = number(field("map size")?, "map size")?;
+        let first_note = number(field("first note")?, "first note")?;
+        let last_note = number(field("last note")?, "last note")?;
+        let middle_note = number(field("middle note")?, "middle note")?;
+        let reference_note = number(field("reference note")?, "reference note")?;
+        let (freq_line, freq_text) = field("reference frequency")?;
+        let reference_frequency: f64 = number((freq_line, freq_text), "reference frequency")?;
+        if !(reference_frequency.is_finite() && reference_frequency > 0.0) {
+            return Err(ScalaError::new(
+                freq_line,
+                ScalaErrorKind::InvalidField("reference frequency"),
+            ));
+        }
+        let (octave_line, octave_text) = field("octave degree")?;
+        let octave_degree: u32 = number((octave_line, octave_text), "octave degree")?;
+        if octave_degree > MAX_DEGREE {
+            return Err(ScalaError::new(
+                octave_line,
+                ScalaErrorKind::InvalidField("octave degree"),
+            ));
+        }
+
+        // The size comes from the file, so let the lines bound the allocation
+        let mapping = lines
+            .take(size)
+            .map(|(line, text)| match first_token(text) {
+                "x" => Ok(None),
+                text => match text.parse::<u32>() {
+                    Ok(degree) if degree <= MAX_DEGREE => Ok(Some(degree)),
+                    _ => Err(ScalaError::new(
+                        line,
+                        ScalaErrorKind::InvalidMapping(text.to_string()),
+                    )),
+                },
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if mapping.len() < size {
+            return Err(ScalaError::new(
+                end,
+                ScalaErrorKind::MissingMappingEntries {
+                    expected: size,
+                    found: mapping.len(),
+                },
+            ));
+        }
+
+        Ok(Self {
+            first_note,
+            last_note,
+            middle_note,
+            reference_note,
+            reference_frequency,
+            octave_degree,
+            mapping,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frequencies::{A432, A440};
+
+    const MEANTONE_SCL: &str = "! meanquar.scl
+!
+1/4-comma meantone scale. Pietro Aaron's temperament (1523)
+ 12
+!
+ 76.04900
+ 193.15686
+ 310.26471
+ 5/4
+ 503.42157
+ 579.47057
+ 696.57843
+ 25/16
+ 889.73529
+ 1006.84314
+ 1082.89214
+ 2/1
+";
+
+    #[test]
+    fn test_parse_scl() {
+        let scale: ScalaScale = MEANTONE_SCL.parse().unwrap();
+        assert_eq!(
+            scale.description,
+            "1/4-comma meantone scale. Pietro Aaron's temperament (1523)"
+        );
+        assert_eq!(scale.pitches.len(), 12);
+        assert_eq!(scale.pitches[3], ScalaPitch::Ratio(5, 4));
+        assert_eq!(scale.period(), 2.0);
+
+        let tuning = scale.to_tuning(261.6).unwrap();
+        let meantone = ScaleTuning::quarter_comma_meantone(261.6);
+        for degree in -12..24 {
+            assert!((tuning.frequency(degree) - meantone.frequency(degree)).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_scl_errors() {
+        let err = "desc\n 3\n 1/1\n abc\n 2/1\n"
+            .parse::<ScalaScale>()
+            .unwrap_err();
+        assert_eq!(err.line, Some(4));
+        assert_eq!(err.kind, ScalaErrorKind::InvalidPitch("abc".to_string()));
+
+        let err = "desc\n 3\n 9/8\n".parse::<ScalaScale>().unwrap_err();
+        assert_eq!(
+            err.kind,
+            ScalaErrorKind::CountMismatch {
+                expected: 3,
+                found: 1
+            }
+        );
+        assert!("! only a comment\n".parse::<ScalaScale>().is_err());
+        // A huge count must fail cleanly rather than pre-allocate
+        let err = "desc\n 99999999999999999\n 2/1\n"
+            .parse::<ScalaScale>()
+            .unwrap_err();
+        assert_eq!(err.line, Some(4));
+        assert!("desc\n 2\n 3/2\n 0/1\n".parse::<ScalaScale>().is_err());
+
+        let unsorted: ScalaScale = "desc\n 2\n 3/2\n 5/4\n".parse().unwrap();
+        let err = unsorted.to_tuning(1.0).unwrap_err();
+        assert_eq!(err.line, None);
+        assert_eq!(err.to_string(), "pitches must ascend");
+    }
+
+    #[test]
+    fn test_scl_roundtrip_and_exports() {
+        for scale in [
+            ScalaScale::just_intonation(),
+            ScalaScale::solfeggio(),
+            ScalaScale::phi_ladder(5),
+            ScalaScale::from_tuning("Pythagorean", &ScaleTuning::pythagorean(1.0)),
+        ] {
+            let parsed: ScalaScale = scale.to_string().parse().unwrap();
+            assert_eq!(parsed.description, scale.description);
+            assert_eq!(parsed.pitches.len(), scale.pitches.len());
+            for (a, b) in parsed.pitches.iter().zip(&scale.pitches) {
+                assert!((a.to_cents() - b.to_cents()).abs() < 1e-5);
+            }
+            assert!(parsed.to_tuning(100.0).is_ok());
+        }
+
+        let solfeggio = ScalaScale::solfeggio();
+        assert_eq!(solfeggio.pitches[2], ScalaPitch::Ratio(4, 3));
+        assert_eq!(ScalaScale::phi_ladder(1).period(), PHI);
+        assert!((ScalaScale::phi_ladder(3).ratio(6) - PHI * PHI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_keyboard_mapping() {
+        let kbm = "! white keys only
+7
+0
+127
+60
+65
+432.0
+12
+0
+2
+4
+5
+7
+9
+11
+";
+        let mapping: KeyboardMapping = kbm.parse().unwrap();
+        assert_eq!(mapping.mapping.len(), 7);
+        assert_eq!(mapping.reference_frequency, A432);
+
+        let chromatic = ScalaScale::from_tuning(
+            "12-TET",
+            &ScaleTuning::new(
+                1.0,
+                &(1..12)
+                    .map(|k| 2.0_f64.powf(k as f64 / 12.0))
+                    .collect::<Vec<_>>(),
+                2.0,
+            ),
+        );
+        // Key 65 is the 6th key of the pattern: degree 9 (A), the reference
+        assert!((mapping.note_frequency(&chromatic, 65).unwrap() - A432).abs() < 1e-9);
+        // One pattern up: degree 12 + 0 (C)
+        let c = mapping.note_frequency(&chromatic, 67).unwrap();
+        assert!((c - A432 * 2.0_f64.powf(3.0 / 12.0)).abs() < 1e-9);
+
+        let linear = KeyboardMapping::linear(60, 69, A440);
+        let parsed: KeyboardMapping = linear.to_string().parse().unwrap();
+        assert_eq!(parsed, linear);
+        assert!((parsed.note_frequency(&chromatic, 81).unwrap() - 880.0).abs() < 1e-9);
+
+        let err = "1\n0\n127\n60\n69\nfast\n"
+            .parse::<KeyboardMapping>()
+            .unwrap_err();
+        assert_eq!(err.line, Some(6));
+        assert_eq!(
+            err.kind,
+            ScalaErrorKind::InvalidField("reference frequency")
+        );
+        let err = "1\n0\n127\n60\n69\n440\n12\ny\n"
+            .parse::<KeyboardMapping>()
+            .unwrap_err();
+        assert_eq!(err.kind, ScalaErrorKind::InvalidMapping("y".to_string()));
+        let err = "1000000000000\n0\n127\n60\n69\n440\n12\n0\nx\n"
+            .parse::<KeyboardMapping>()
+            .unwrap_err();
+        assert_eq!(
+            err.kind,
+            ScalaErrorKind::MissingMappingEntries {
+                expected: 1_000_000_000_000,
+                found: 2
+            }
+        );
+
+        // Degrees that would overflow are rejected when parsing...
+        let err = "1\n0\n127\n60\n69\n440\n3000000000\n0\n"
+            .parse::<KeyboardMapping>()
+            .unwrap_err();
+        assert_eq!(err.line, Some(7));
+        assert_eq!(err.kind, ScalaErrorKind::InvalidField("octave degree"));
+        let err = "1\n0\n127\n60\n69\n440\n12\n2147483648\n"
+            .parse::<KeyboardMapping>()
+            .unwrap_err();
+        assert_eq!(
+            err.kind,
+            ScalaErrorKind::InvalidMapping("2147483648".to_string())
+        );
+        // ...and leave the note unmapped when they still overflow
+        let huge: KeyboardMapping = "1\n0\n127\n60\n60\n440\n2000000000\n0\n".parse().unwrap();
+        assert_eq!(huge.note_frequency(&chromatic, 60), Some(440.0));
+        assert_eq!(huge.note_frequency(&chromatic, 62), None);
+        let unchecked = KeyboardMapping {
+            octave_degree: u32::MAX,
+            ..huge
+        };
+        assert_eq!(unchecked.note_frequency(&chromatic, 61), None);
+    }
+}
@@ -0,0 +1,467 @@
+//! Spectrum analysis - FFT, windows, Welch averaging and peak matching to known frequencies.
+//!
+//! (c) 2025 Anywave Creations
+//! MIT License
+
+use std::f64::consts::TAU;
+use std::fmt;
+
+use crate::frequencies::{A432, A440};
+use crate::note::Note;
+use crate::registry::{nearest_known, FrequencyMatch};
+use crate::tolerance::Tolerance;
+
+/// Peaks are annotated with known frequencies within a quarter tone
+const PEAK_MATCH_CENTS: f64 = 50.0;
+
+/// A complex number, as produced by the FFT
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex {
+    /// Real part
+    pub re: f64,
+    /// Imaginary part
+    pub im: f64,
+}
+
+impl Complex {
+    /// Create a complex number
+    pub const fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    /// Squared magnitude
+    pub fn norm_sqr(&self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Magnitude
+    pub fn abs(&self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    /// Phase angle in radians (-π to π)
+    pub fn arg(&self) -> f64 {
+        self.im.atan2(self.re)
+    }
+}
+
+/// In-place iterative radix-2 FFT
+///
+/// # Panics
+/// Panics if the length is not a power of two
+pub fn fft(data: &mut [Complex]) {
+    let n = data.len();
+    if !n.is_power_of_two() {
+        panic!("FFT length must be a power of two");
+    }
+
+    // Bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = -TAU / len as f64;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (sin, cos) = (angle * k as f64).sin_cos();
+                let a = data[start + k];
+                let b = data[start + k + len / 2];
+                let t = Complex::new(b.re * cos - b.im * sin, b.re * sin + b.im * cos);
+                data[start + k] = Complex::new(a.re + t.re, a.im + t.im);
+                data[start + k + len / 2] = Complex::new(a.re - t.re, a.im - t.im);
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// FFT of a real signal, zero-padded to the next power of two.
+///
+/// # Returns
+/// The non-negative frequency bins 0..=N/2
+pub fn rfft(samples: &[f64]) -> Vec<Complex> {
+    let n = samples.len().max(1).next_power_of_two();
+    let mut data: Vec<Complex> = samples.iter().map(|&x| Complex::new(x, 0.0)).collect();
+    data.resize(n, Complex::default());
+    fft(&mut data);
+    data.truncate(n / 2 + 1);
+    data
+}
+
+/// Window function applied to each segment before the FFT
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Window {
+    Rectangular,
+    Hann,
+    Hamming,
+    Blackman,
+    /// 4-term Blackman-Harris, for low leakage
+    BlackmanHarris,
+}
+
+impl Window {
+    /// Periodic window coefficients for a segment length
+    pub fn coefficients(&self, len: usize) -> Vec<f64> {
+        let cosine_sum = |a: &[f64]| -> Vec<f64> {
+            (0..len)
+                .map(|i| {
+                    let x = TAU * i as f64 / len as f64;
+                    a.iter()
+                        .enumerate()
+                        .map(|(k, &ak)| {
+                            let sign = if k.is_multiple_of(2) { 1.0 } else { -1.0 };
+                            sign * ak * (k as f64 * x).cos()
+                        })
+                        .sum()
+                })
+                .collect()
+        };
+
+        match self {
+            Self::Rectangular => vec![1.0; len],
+            Self::Hann => cosine_sum(&[0.5, 0.5]),
+            Self::Hamming => cosine_sum(&[0.54, 0.46]),
+            Self::Blackman => cosine_sum(&[0.42, 0.5, 0.08]),
+            Self::BlackmanHarris => cosine_sum(&[0.35875, 0.48829, 0.14128, 0.01168]),
+        }
+    }
+}
+
+/// The nearest 12-TET note to a measured frequency on one pitch standard
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoteMatch {
+    /// Nearest note, with the measured frequency's offset from it in cents
+    pub note: Note,
+    /// Frequency of A4 the note is tuned to (`A432` or `A440`)
+    pub reference: f64,
+}
+
+impl fmt::Display for NoteMatch {
+    /// Formats as e.g. "C4 (A440)", without the cents offset
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (A{})", self.note.with_cents(0.0), self.reference)
+    }
+}
+
+/// Find the nearest 12-TET note to a frequency on either A432 or A440.
+///
+/// # Returns
+/// Whichever note is closer in cents, or None if the frequency is not
+/// positive and finite
+pub fn nearest_note(frequency: f64) -> Option<NoteMatch> {
+    if !(frequency > 0.0 && frequency.is_finite()) {
+        return None;
+    }
+
+    [A432, A440]
+        .into_iter()
+        .map(|reference| NoteMatch {
+            note: Note::from_frequency(frequency, reference),
+            reference,
+        })
+        .min_by(|a, b| a.note.cents.abs().total_cmp(&b.note.cents.abs()))
+}
+
+/// A spectral peak
+#[derive(Debug, Clone, PartialEq)]
+pub struct Peak {
+    /// Interpolated frequency in Hz
+    pub frequency: f64,
+    /// Interpolated power (A²/2 for a sine of amplitude A)
+    pub power: f64,
+    /// Nearest known crate frequency, or an octave of one, within a quarter tone
+    pub nearest: Option<FrequencyMatch>,
+    /// Nearest 12-TET note on A432 or A440
+    pub note: Option<NoteMatch>,
+}
+
+/// One-sided power spectrum
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spectrum {
+    /// Samples per second of the analysed signal
+    pub sample_rate: f64,
+    /// FFT length (a power of two)
+    pub fft_size: usize,
+    power: Vec<f64>,
+}
+
+impl Spectrum {
+    /// Single windowed periodogram of a whole buffer
+    ///
+    /// # Panics
+    /// Panics if sample_rate <= 0
+    pub fn periodogram(samples: &[f32], sample_rate: f64, window: Window) -> Self {
+        Self::welch(samples, sample_rate, samples.len().max(1), 0.0, window)
+    }
+
+    /// Welch's method: average the periodograms of overlapping windowed segments.
+    ///
+    /// # Arguments
+    /// * `samples` - Mono sample buffer
+    /// * `sample_rate` - Samples per second
+    /// * `segment_len` - Samples per segment (zero-padded to a power of two)
+    /// * `overlap` - Fraction of each segment shared with the next (0 to <1, 0.5 is typical)
+    /// * `window` - Window applied to each segment
+    ///
+    /// # Panics
+    /// Panics if sample_rate <= 0, segment_len is 0 or overlap is outside 0 to <1
+    pub fn welch(
+        samples: &[f32],
+        sample_rate: f64,
+        segment_len: usize,
+        overlap: f64,
+        window: Window,
+    ) -> Self {
+        if sample_rate.is_nan() || sample_rate <= 0.0 {
+            panic!("Sample rate must be positive");
+        }
+        if segment_len == 0 {
+            panic!("segment_len must be >= 1");
+        }
+        if !(0.0..1.0).contains(&overlap) {
+            panic!("Overlap must be between 0 and 1");
+        }
+
+        let coefficients = window.coefficients(segment_len);
+        let gain: f64 = coefficients.iter().sum();
+        let fft_size = segment_len.next_power_of_two();
+        let step = ((segment_len as f64 * (1.0 - overlap)).round() as usize).max(1);
+
+        let mut power = vec![0.0; fft_size / 2 + 1];
+        let mut segments = 0;
+        let mut start = 0;
+        loop {
+            let end = (start + segment_len).min(samples.len());
+            let mut segment: Vec<f64> = samples[start..end]
+                .iter()
+                .zip(&coefficients)
+                .map(|(&x, &w)| x as f64 * w)
+                .collect();
+            segment.resize(segment_len, 0.0);
+
+            for (k, bin) in rfft(&segment).iter().enumerate() {
+                // Double all but DC and Nyquist to fold in negative frequencies
+                let fold = if k == 0 || k == fft_size / 2 {
+                    1.0
+                } else {
+                    2.0
+                };
+                power[k] += fold * bin.norm_sqr() / (gain * gain);
+            }
+            segments += 1;
+
+            start += step;
+            if start + segment_len > samples.len() {
+                break;
+            }
+        }
+
+        for p in &mut power {
+            *p /= segments as f64;
+        }
+
+        Self {
+            sample_rate,
+            fft_size,
+            power,
+        }
+    }
+
+    /// Power per bin, from DC to Nyquist
+    pub fn power(&self) -> &[f64] {
+        &self.power
+    }
+
+    /// Frequency spacing between bins in Hz
+    pub fn bin_width(&self) -> f64 {
+        self.sample_rate / self.fft_size as f64
+    }
+
+    /// Centre frequency of a bin in Hz
+    pub fn bin_frequency(&self, bin: usize) -> f64 {
+        bin as f64 * self.bin_width()
+    }
+
+    /// Find local maxima above a power threshold.
+    ///
+    /// Peak positions are refined by fitting a parabola through the log power
+    /// of the peak bin and its neighbours, then annotated with the nearest
+    /// known crate frequency and the nearest A432 or A440 note.
+    ///
+    /// # Arguments
+    /// * `min_power` - Minimum bin power for a peak
+    ///
+    /// # Returns
+    /// Peaks sorted by power, strongest first
+    pub fn peaks(&self, min_power: f64) -> Vec<Peak> {
+        let mut peaks: Vec<Peak> = (1..self.power.len().saturating_sub(1))
+            .filter(|&k| {
+                let p = self.power[k];
+                p >= min_power && p > self.power[k - 1] && p >= self.power[k + 1]
+            })
+            .map(|k| {
+                let (offset, power) = self.interpolate(k);
+                let frequency = (k as f64 + offset) * self.bin_width();
+                Peak {
+                    frequency,
+                    power,
+                    nearest: nearest_known(frequency, Tolerance::Cents(PEAK_MATCH_CENTS))
+                        .into_iter()
+                        .next(),
+                    note: nearest_note(frequency),
+                }
+            })
+            .collect();
+
+        peaks.sort_by(|a, b| b.power.total_cmp(&a.power));
+        peaks
+    }
+
+    /// Parabolic interpolation on log power around a bin
+    ///
+    /// # Returns
+    /// (offset in bins from -0.5 to 0.5, interpolated power)
+    fn interpolate(&self, k: usize) -> (f64, f64) {
+        let floor = f64::MIN_POSITIVE;
+        let a = self.power[k - 1].max(floor).ln();
+        let b = self.power[k].max(floor).ln();
+        let c = self.power[k + 1].max(floor).ln();
+
+        let denominator = a - 2.0 * b + c;
+        if denominator >= 0.0 {
+            return (0.0, self.power[k]);
+        }
+        let offset = (0.5 * (a - c) / denominator).clamp(-0.5, 0.5);
+        (offset, (b - 0.25 * (a - c) * offset).exp())
+    }
+}
+
+/// Smallest power-of-two segment length whose bin width is at most the requested resolution
+///
+/// # Arguments
+/// * `sample_rate` - Samples per second
+/// * `resolution` - Desired bin width in Hz
+///
+/// # Panics
+/// Panics if sample_rate or resolution is not positive and finite, or if the
+/// segment length would not fit in a usize
+pub fn segment_length_for(sample_rate: f64, resolution: f64) -> usize {
+    if !(sample_rate.is_finite() && sample_rate > 0.0) {
+        panic!("Sample rate must be positive");
+    }
+    if !(resolution.is_finite() && resolution > 0.0) {
+        panic!("Resolution must be positive");
+    }
+    let bins = (sample_rate / resolution).ceil();
+    if bins > (usize::MAX / 2 + 1) as f64 {
+        panic!("Segment length must fit in a usize");
+    }
+    (bins as usize).next_power_of_two()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frequencies::{cents_difference, SCHUMANN_FUNDAMENTAL, SOLFEGGIO_MI};
+    use crate::synth::Synth;
+
+    #[test]
+    fn test_fft_matches_dft() {
+        let samples: Vec<f64> = (0..16).map(|i| ((i * 7) % 5) as f64 - 2.0).collect();
+        let bins = rfft(&samples);
+        assert_eq!(bins.len(), 9);
+
+        for (k, bin) in bins.iter().enumerate() {
+            let mut expected = Complex::default();
+            for (n, &x) in samples.iter().enumerate() {
+                let angle = -TAU * (k * n) as f64 / 16.0;
+                expected.re += x * angle.cos();
+                expected.im += x * angle.sin();
+            }
+            assert!((bin.re - expected.re).abs() < 1e-9);
+            assert!((bin.im - expected.im).abs() < 1e-9);
+        }
+        assert_eq!(rfft(&[1.0; 5]).len(), 5);
+    }
+
+    #[test]
+    fn test_windows() {
+        let hann = Window::Hann.coefficients(8);
+        assert_eq!(hann[0], 0.0);
+        assert!((hann[4] - 1.0).abs() < 1e-12);
+        assert!((hann.iter().sum::<f64>() - 4.0).abs() < 1e-12);
+        assert!((Window::Hamming.coefficients(4)[0] - 0.08).abs() < 1e-12);
+        assert!(Window::BlackmanHarris.coefficients(64)[0].abs() < 1e-4);
+        assert_eq!(Window::Rectangular.coefficients(3), [1.0; 3]);
+    }
+
+    #[test]
+    fn test_peak_interpolation_and_annotation() {
+        let rate = 4096.0;
+        let synth = Synth::new(4096).with_amplitude(0.6);
+        let samples = synth.sine(SOLFEGGIO_MI + 0.37, 1.0);
+
+        let spectrum = Spectrum::periodogram(&samples, rate, Window::Hann);
+        assert_eq!(spectrum.fft_size, 4096);
+        assert_eq!(spectrum.bin_width(), 1.0);
+
+        let peak = &spectrum.peaks(1e-3)[0];
+        assert!((peak.frequency - (SOLFEGGIO_MI + 0.37)).abs() < 0.05);
+        assert!((peak.power - 0.18).abs() < 0.01);
+
+        let nearest = peak.nearest.as_ref().unwrap();
+        assert_eq!(nearest.known.name, "Solfeggio MI");
+        assert_eq!(nearest.octaves, 0);
+        assert!((nearest.cents - cents_difference(SOLFEGGIO_MI, peak.frequency)).abs() < 1e-12);
+
+        let note = peak.note.unwrap();
+        assert_eq!(note.to_string(), "C5 (A440)");
+        assert!((note.note.frequency(A440) - peak.frequency).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_welch_schumann() {
+        let rate = 256.0;
+        let synth = Synth::new(256);
+        let mut samples = synth.sine(SCHUMANN_FUNDAMENTAL, 60.0);
+        // Deterministic broadband noise
+        let mut seed = 12345_u32;
+        for s in &mut samples {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            *s += (seed >> 16) as f32 / 32_768.0 - 1.0;
+        }
+
+        let segment = segment_length_for(rate, 0.25);
+        assert_eq!(segment, 1024);
+        let spectrum = Spectrum::welch(&samples, rate, segment, 0.5, Window::Blackman);
+        assert_eq!(spectrum.power().len(), 513);
+
+        let peak = &spectrum.peaks(0.0)[0];
+        assert!((peak.frequency - SCHUMANN_FUNDAMENTAL).abs() < 0.05);
+        assert_eq!(peak.nearest.as_ref().unwrap().known.name, "Schumann 1");
+
+        // Notes between the registry's entries are still named
+        let note = nearest_note(261.7).unwrap();
+        assert_eq!(note.to_string(), "C4 (A440)");
+        assert!((note.note.cents - cents_difference(261.6256, 261.7)).abs() < 1e-3);
+        assert_eq!(nearest_note(256.0).unwrap().to_string(), "C4 (A432)");
+        assert!(nearest_note(0.0).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Resolution must be positive")]
+    fn test_segment_length_rejects_zero_resolution() {
+        segment_length_for(256.0, 0.0);
+    }
+}
@@ -0,0 +1,311 @@
+//! Tone synthesis - PCM sample buffers for sine, additive and binaural tones.
+//!
+//! (c) 2025 Anywave Creations
+//! MIT License
+
+use std::f64::consts::TAU;
+
+use crate::frequencies::harmonic_of;
+
+/// ADSR amplitude envelope; times in seconds, sustain as a 0-1 level
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Envelope {
+    /// Rise from silence to full amplitude, in seconds (>= 0)
+    pub attack: f64,
+    /// Fall from full amplitude to the sustain level, in seconds (>= 0)
+    pub decay: f64,
+    /// Level held between decay and release, as a fraction of full amplitude (0-1)
+    pub sustain: f64,
+    /// Fall from the sustain level to silence at the end of the tone, in seconds (>= 0)
+    pub release: f64,
+}
+
+impl Envelope {
+    /// Full amplitude throughout (clicks at the ends)
+    pub const CONSTANT: Envelope = Envelope::new(0.0, 0.0, 1.0, 0.0);
+
+    /// Create an envelope
+    ///
+    /// # Panics
+    /// Panics if a time is negative or NaN, or sustain is outside 0-1 or NaN
+    pub const fn new(attack: f64, decay: f64, sustain: f64, release: f64) -> Self {
+        if !(attack >= 0.0 && decay >= 0.0 && release >= 0.0) {
+            panic!("Envelope times must be non-negative");
+        }
+        if !(sustain >= 0.0 && sustain <= 1.0) {
+            panic!("Sustain must be between 0 and 1");
+        }
+        Self {
+            attack,
+            decay,
+            sustain,
+            release,
+        }
+    }
+
+    /// Linear fade in and out at full sustain
+    pub const fn fade(seconds: f64) -> Self {
+        Self::new(seconds, 0.0, 1.0, seconds)
+    }
+
+    /// Gain at a time within a tone of the given duration.
+    ///
+    /// The release ends at the end of the tone; if the segments overlap,
+    /// the lower gain wins.
+    pub fn gain(&self, time: f64, duration: f64) -> f64 {
+        if time < 0.0 || time > duration {
+            return 0.0;
+        }
+
+        let level = if time < self.attack {
+            time / self.attack
+        } else if time < self.attack + self.decay {
+            1.0 - (1.0 - self.sustain) * (time - self.attack) / self.decay
+        } else {
+            self.sustain
+        };
+
+        let remaining = duration - time;
+        if remaining < self.release {
+            level * remaining / self.release
+        } else {
+            level
+        }
+    }
+}
+
+impl Default for Envelope {
+    fn default() -> Self {
+        Self::CONSTANT
+    }
+}
+
+/// One sine component of an additive tone
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Partial {
+    /// Frequency in Hz
+    pub frequency: f64,
+    /// Relative amplitude
+    pub amplitude: f64,
+    /// Starting phase in radians
+    pub phase: f64,
+}
+
+impl Partial {
+    /// Create a partial starting at zero phase
+    pub const fn new(frequency: f64, amplitude: f64) -> Self {
+        Self {
+            frequency,
+            amplitude,
+            phase: 0.0,
+        }
+    }
+
+    /// Harmonic series of a fundamental with 1/n amplitudes (a sawtooth spectrum)
+    ///
+    /// # Arguments
+    /// * `fundamental` - Frequency of the first harmonic in Hz
+    /// * `count` - Number of harmonics
+    pub fn harmonic_series(fundamental: f64, count: u32) -> Vec<Partial> {
+        (1..=count)
+            .map(|n| Partial::new(harmonic_of(fundamental, n), 1.0 / n as f64))
+            .collect()
+    }
+}
+
+/// Renders tones as f32 sample buffers in the range -1 to 1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Synth {
+    /// Samples per second
+    pub sample_rate: u32,
+    /// Peak amplitude (0-1)
+    pub amplitude: f64,
+    /// Envelope applied to every tone
+    pub envelope: Envelope,
+}
+
+impl Synth {
+    /// Create a synth at full amplitude with a constant envelope
+    ///
+    /// # Panics
+    /// Panics if sample_rate is 0
+    pub const fn new(sample_rate: u32) -> Self {
+        if sample_rate == 0 {
+            panic!("sample_rate must be >= 1");
+        }
+        Self {
+            sample_rate,
+            amplitude: 1.0,
+            envelope: Envelope::CONSTANT,
+        }
+    }
+
+    /// Return this synth with a peak amplitude
+    pub const fn with_amplitude(mut self, amplitude: f64) -> Self {
+        self.amplitude = amplitude;
+        self
+    }
+
+    /// Return this synth with an envelope
+    pub const fn with_envelope(mut self, envelope: Envelope) -> Self {
+        self.envelope = envelope;
+        self
+    }
+
+    /// Number of samples in a duration
+    ///
+    /// # Panics
+    /// Panics if duration is negative, infinite or NaN
+    pub fn sample_count(&self, duration: f64) -> usize {
+        if !(duration >= 0.0 && duration.is_finite()) {
+            panic!("duration must be non-negative and finite");
+        }
+        (duration * self.sample_rate as f64).round() as usize
+    }
+
+    /// Render a sine tone
+    pub fn sine(&self, frequency: f64, duration: f64) -> Vec<f32> {
+        self.additive(&[Partial::new(frequency, 1.0)], duration)
+    }
+
+    /// Render several frequencies at equal amplitude, e.g. `SOLFEGGIO_FREQUENCIES`
+    pub fn chord(&self, frequencies: &[f64], duration: f64) -> Vec<f32> {
+        let partials: Vec<Partial> = frequencies.iter().map(|&f| Partial::new(f, 1.0)).collect();
+        self.additive(&partials, duration)
+    }
+
+    /// Render a sum of partials.
+    ///
+    /// Amplitudes are scaled by their total so the output never exceeds
+    /// the synth's amplitude.
+    pub fn additive(&self, partials: &[Partial], duration: f64) -> Vec<f32> {
+        let count = self.sample_count(duration);
+        let total: f64 = partials.iter().map(|p| p.amplitude.abs()).sum();
+        if total == 0.0 {
+            return vec![0.0; count];
+        }
+
+        let rate = self.sample_rate as f64;
+        let scale = self.amplitude / total;
+        (0..count)
+            .map(|i| {
+                let t = i as f64 / rate;
+                let value: f64 = partials
+                    .iter()
+                    .map(|p| p.amplitude * (TAU * p.frequency * t + p.phase).sin())
+                    .sum();
+                (value * scale * self.envelope.gain(t, duration)) as f32
+            })
+            .collect()
+    }
+
+    /// Render a binaural beat as interleaved stereo (left, right, left, ...).
+    ///
+    /// # Arguments
+    /// * `carrier` - Left ear frequency in Hz
+    /// * `beat` - Beat frequency in Hz; the right ear hears carrier + beat
+    /// * `duration` - Length in seconds
+    pub fn binaural(&self, carrier: f64, beat: f64, duration: f64) -> Vec<f32> {
+        let left = self.sine(carrier, duration);
+        let right = self.sine(carrier + beat, duration);
+        interleave(&left, &right)
+    }
+}
+
+/// Interleave two mono buffers into stereo, truncating to the shorter one
+pub fn interleave(left: &[f32], right: &[f32]) -> Vec<f32> {
+    left.iter().zip(right).flat_map(|(&l, &r)| [l, r]).collect()
+}
+
+/// Convert f32 samples to 16-bit PCM, clamping to -1..1
+pub fn to_i16(samples: &[f32]) -> Vec<i16> {
+    samples
+        .iter()
+        .map(|&s| (s.clamp(-1.0, 1.0) * i16::MAX as f32).round() as i16)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frequencies::{A432, SCHUMANN_FUNDAMENTAL, SOLFEGGIO_FREQUENCIES};
+
+    /// Count upward zero crossings
+    fn crossings(samples: &[f32]) -> usize {
+        samples
+            .windows(2)
+            .filter(|w| w[0] < 0.0 && w[1] >= 0.0)
+            .count()
+    }
+
+    #[test]
+    fn test_sine() {
+        let synth = Synth::new(48_000);
+        let tone = synth.sine(A432, 1.0);
+        assert_eq!(tone.len(), 48_000);
+        assert_eq!(tone[0], 0.0);
+        assert!((crossings(&tone) as i64 - 432).abs() <= 1);
+
+        let peak = tone.iter().fold(0.0_f32, |m, s| m.max(s.abs()));
+        assert!((peak - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_additive_and_chord() {
+        let synth = Synth::new(8_000).with_amplitude(0.5);
+        let chord = synth.chord(&SOLFEGGIO_FREQUENCIES, 0.5);
+        assert_eq!(chord.len(), 4_000);
+        assert!(chord.iter().all(|s| s.abs() <= 0.5));
+
+        let series = Partial::harmonic_series(SCHUMANN_FUNDAMENTAL * 16.0, 4);
+        assert_eq!(series[2].frequency, SCHUMANN_FUNDAMENTAL * 48.0);
+        assert_eq!(series[3].amplitude, 0.25);
+        assert!(synth.additive(&[], 0.1).iter().all(|&s| s == 0.0));
+    }
+
+    #[test]
+    fn test_envelope() {
+        let envelope = Envelope::new(0.1, 0.1, 0.5, 0.2);
+        assert_eq!(envelope.gain(0.0, 1.0), 0.0);
+        assert!((envelope.gain(0.05, 1.0) - 0.5).abs() < 1e-12);
+        assert!((envelope.gain(0.15, 1.0) - 0.75).abs() < 1e-12);
+        assert_eq!(envelope.gain(0.5, 1.0), 0.5);
+        assert!((envelope.gain(0.9, 1.0) - 0.25).abs() < 1e-12);
+        assert_eq!(envelope.gain(1.0, 1.0), 0.0);
+
+        let tone = Synth::new(1_000)
+            .with_envelope(Envelope::fade(0.1))
+            .sine(50.0, 1.0);
+        assert!(tone[..20].iter().all(|s| s.abs() < 0.2));
+    }
+
+    #[test]
+    fn test_binaural_and_pcm() {
+        let stereo = Synth::new(44_100).binaural(200.0, 10.0, 1.0);
+        assert_eq!(stereo.len(), 88_200);
+        let left: Vec<f32> = stereo.iter().step_by(2).copied().collect();
+        let right: Vec<f32> = stereo.iter().skip(1).step_by(2).copied().collect();
+        assert!((crossings(&left) as i64 - 200).abs() <= 1);
+        assert!((crossings(&right) as i64 - 210).abs() <= 1);
+
+        assert_eq!(to_i16(&[0.0, 1.0, -1.0, 2.0]), [0, 32767, -32767, 32767]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_sample_rate_panics() {
+        Synth::new(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "duration must be non-negative and finite")]
+    fn test_infinite_duration_panics() {
+        Synth::new(44_100).sine(440.0, f64::INFINITY);
+    }
+
+    #[test]
+    #[should_panic(expected = "Sustain must be between 0 and 1")]
+    fn test_nan_sustain_panics() {
+        Envelope::new(0.01, 0.01, f64::NAN, 0.01);
+    }
+}
@@ -0,0 +1,592 @@
+//! Data tables - load and save material and frequency tables as TOML, JSON or CSV.
+//!
+//! Requires the `serde` feature plus one or more of `toml`, `json` and `csv`.
+//!
+//! (c) 2025 Anywave Creations
+//! MIT License
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::frequencies::MaterialProperties;
+use crate::materials::{MaterialCatalog, MaterialError};
+use crate::registry::{FrequencyCategory, KnownFrequency};
+
+/// File format of a table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TableFormat {
+    /// TOML, with rows as an array of tables (`toml` feature)
+    Toml,
+    /// JSON array of objects (`json` feature)
+    Json,
+    /// CSV with a header row (`csv` feature)
+    Csv,
+}
+
+impl TableFormat {
+    /// Detect the format from a file extension (case-insensitive)
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "toml" => Some(Self::Toml),
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+
+    /// Cargo feature that enables this format
+    pub const fn feature(&self) -> &'static str {
+        match self {
+            Self::Toml => "toml",
+            Self::Json => "json",
+            Self::Csv => "csv",
+        }
+    }
+}
+
+/// What went wrong while reading or writing a table
+#[derive(Debug)]
+pub enum TableErrorKind {
+    /// Underlying I/O failure
+    Io(io::Error),
+    /// Malformed file or invalid row
+    Parse(String),
+    /// File extension is not .toml, .json or .csv
+    UnknownFormat(PathBuf),
+    /// Format support was not compiled in
+    FormatDisabled(TableFormat),
+}
+
+/// Error reading or writing a table, with the file and line when known
+#[derive(Debug)]
+pub struct TableError {
+    /// File being read or written, if the table came from disk
+    pub path: Option<PathBuf>,
+    /// 1-based line number
+    pub line: Option<usize>,
+    /// What went wrong
+    pub kind: TableErrorKind,
+}
+
+impl TableError {
+    fn new(kind: TableErrorKind) -> Self {
+        Self {
+            path: None,
+            line: None,
+            kind,
+        }
+    }
+
+    fn parse(line: Option<usize>, message: impl Into<String>) -> Self {
+        Self {
+            path: None,
+            line,
+            kind: TableErrorKind::Parse(message.into()),
+        }
+    }
+
+    fn in_file(mut self, path: &Path) -> Self {
+        self.path = Some(path.to_path_buf());
+        self
+    }
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{}:", path.display())?;
+        }
+        if let Some(line) = self.line {
+            write!(f, "{}:", line)?;
+        }
+        if self.path.is_some() || self.line.is_some() {
+            write!(f, " ")?;
+        }
+        match &self.kind {
+            TableErrorKind::Io(err) => write!(f, "I/O error: {}", err),
+            TableErrorKind::Parse(message) => write!(f, "{}", message),
+            TableErrorKind::UnknownFormat(path) => {
+                write!(f, "unknown table format for '{}'", path.display())
+            }
+            TableErrorKind::FormatDisabled(format) => {
+                write!(
+                    f,
+                    "{} support requires the '{}' feature",
+                    format.feature(),
+                    format.feature()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for TableError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            TableErrorKind::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for TableError {
+    fn from(err: io::Error) -> Self {
+        Self::new(TableErrorKind::Io(err))
+    }
+}
+
+/// One row of a material table
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "MaterialRow")]
+pub struct MaterialRecord {
+    /// Material name, trimmed and non-empty
+    pub name: String,
+    /// Base frequency in Hz
+    pub frequency: f64,
+    /// Affinity for alpha coherence (0-1)
+    pub alpha_affinity: f64,
+    /// Scalar field conductivity (0-1)
+    pub conductivity: f64,
+}
+
+impl MaterialRecord {
+    /// Properties of this material
+    pub const fn properties(&self) -> MaterialProperties {
+        MaterialProperties::new(self.frequency, self.alpha_affinity, self.conductivity)
+    }
+}
+
+/// Unvalidated material row, checked on conversion so errors carry a position
+#[derive(Deserialize)]
+struct MaterialRow {
+    name: String,
+    frequency: f64,
+    alpha_affinity: f64,
+    conductivity: f64,
+}
+
+impl TryFrom<MaterialRow> for MaterialRecord {
+    type Error = MaterialError;
+
+    fn try_from(row: MaterialRow) -> Result<Self, Self::Error> {
+        let record = Self {
+            name: row.name.trim().to_string(),
+            frequency: row.frequency,
+            alpha_affinity: row.alpha_affinity,
+            conductivity: row.conductivity,
+        };
+        if record.name.is_empty() {
+            return Err(MaterialError::EmptyName);
+        }
+        record.properties().validate()?;
+        Ok(record)
+    }
+}
+
+/// One row of a frequency table
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "FrequencyRow")]
+pub struct FrequencyRecord {
+    /// Frequency name, trimmed and non-empty
+    pub name: String,
+    /// Frequency in Hz
+    pub frequency: f64,
+    /// Kind of frequency, written in snake_case (e.g. "pitch_standard")
+    pub category: FrequencyCategory,
+    /// Free-form description; may be empty
+    pub description: String,
+}
+
+impl From<&KnownFrequency> for FrequencyRecord {
+    fn from(known: &KnownFrequency) -> Self {
+        Self {
+            name: known.name.to_string(),
+            frequency: known.frequency,
+            category: known.category,
+            description: known.description.to_string(),
+        }
+    }
+}
+
+/// Unvalidated frequency row
+#[derive(Deserialize)]
+struct FrequencyRow {
+    name: String,
+    frequency: f64,
+    category: FrequencyCategory,
+    #[serde(default)]
+    description: String,
+}
+
+/// Why a frequency row was rejected
+#[derive(Debug)]
+enum FrequencyRowError {
+    EmptyName,
+    InvalidFrequency(f64),
+}
+
+impl fmt::Display for FrequencyRowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyName => write!(f, "frequency name is empty"),
+            Self::InvalidFrequency(v) => write!(f, "frequency {} must be positive", v),
+        }
+    }
+}
+
+impl TryFrom<FrequencyRow> for FrequencyRecord {
+    type Error = FrequencyRowError;
+
+    fn try_from(row: FrequencyRow) -> Result<Self, Self::Error> {
+        let name = row.name.trim().to_string();
+        if name.is_empty() {
+            return Err(FrequencyRowError::EmptyName);
+        }
+        if !(row.frequency.is_finite() && row.frequency > 0.0) {
+            return Err(FrequencyRowError::InvalidFrequency(row.frequency));
+        }
+        Ok(Self {
+            name,
+            frequency: row.frequency,
+            category: row.category,
+            description: row.description,
+        })
+    }
+}
+
+/// TOML files hold rows as arrays of tables: `[[material]]` or `[[frequency]]`
+#[derive(Serialize, Deserialize)]
+struct MaterialTable {
+    #[serde(default)]
+    material: Vec<MaterialRecord>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct FrequencyTable {
+    #[serde(default)]
+    frequency: Vec<FrequencyRecord>,
+}
+
+/// Parse rows in a format.
+///
+/// JSON and CSV are flat lists of rows; TOML wraps them in a `Table`, which
+/// `unwrap` turns back into rows.
+fn parse_rows<T, Table>(
+    text: &str,
+    format: TableFormat,
+    unwrap: impl FnOnce(Table) -> Vec<T>,
+) -> Result<Vec<T>, TableError>
+where
+    T: DeserializeOwned,
+    Table: DeserializeOwned,
+{
+    match format {
+        #[cfg(feature = "toml")]
+        TableFormat::Toml => parse_toml(text).map(unwrap),
+        #[cfg(feature = "json")]
+        TableFormat::Json => serde_json::from_str(text).map_err(|err| {
+            // Drop serde_json's " at line L column C" suffix; the line is kept separately
+            let mut message = err.to_string();
+            if let Some(i) = message.rfind(" at line ") {
+                message.truncate(i);
+            }
+            TableError::parse(Some(err.line()), message)
+        }),
+        #[cfg(feature = "csv")]
+        TableFormat::Csv => {
+            let mut reader = csv::ReaderBuilder::new()
+                .trim(csv::Trim::All)
+                .from_reader(text.as_bytes());
+            reader
+                .deserialize()
+                .collect::<Result<Vec<T>, csv::Error>>()
+                .map_err(|err| {
+                    let line = err.position().map(|p| p.line() as usize);
+                    let message = match err.kind() {
+                        csv::ErrorKind::Deserialize { err, .. } => err.to_string(),
+                        _ => err.to_string(),
+                    };
+                    TableError::parse(line, message)
+                })
+        }
+        #[allow(unreachable_patterns)]
+        _ => {
+            let _ = (text, unwrap);
+            Err(TableError::new(TableErrorKind::FormatDisabled(format)))
+        }
+    }
+}
+
+/// Parse a TOML document, reporting the line of the failing span
+#[cfg(feature = "toml")]
+fn parse_toml<T: DeserializeOwned>(text: &str) -> Result<T, TableError> {
+    toml::from_str(text).map_err(|err| {
+        let line = err
+            .span()
+            .map(|span| text[..span.start].matches('\n').count() + 1);
+        TableError::parse(line, err.message())
+    })
+}
+
+/// Serialize rows in a format, wrapping them with `wrap` for TOML
+fn format_rows<T, Table>(
+    rows: Vec<T>,
+    format: TableFormat,
+    wrap: impl FnOnce(Vec<T>) -> Table,
+) -> Result<String, TableError>
+where
+    T: Serialize,
+    Table: Serialize,
+{
+    match format {
+        #[cfg(feature = "toml")]
+        TableFormat::Toml => {
+            toml::to_string(&wrap(rows)).map_err(|err| TableError::parse(None, err.to_string()))
+        }
+        #[cfg(feature = "json")]
+        TableFormat::Json => serde_json::to_string_pretty(&rows)
+            .map(|json| json + "\n")
+            .map_err(|err| TableError::parse(None, err.to_string())),
+        #[cfg(feature = "csv")]
+        TableFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            for row in &rows {
+                writer
+                    .serialize(row)
+                    .map_err(|err| TableError::parse(None, err.to_string()))?;
+            }
+            let bytes = writer
+                .into_inner()
+                .map_err(|err| TableError::from(err.into_error()))?;
+            Ok(String::from_utf8(bytes).expect("CSV writer produces UTF-8"))
+        }
+        #[allow(unreachable_patterns)]
+        _ => {
+            let _ = (rows, wrap);
+            Err(TableError::new(TableErrorKind::FormatDisabled(format)))
+        }
+    }
+}
+
+/// Parse a material table into a catalogue holding only its rows.
+///
+/// Later rows replace earlier rows with the same name.
+pub fn parse_materials(text: &str, format: TableFormat) -> Result<MaterialCatalog, TableError> {
+    let rows = parse_rows(text, format, |table: MaterialTable| table.material)?;
+
+    let mut catalog = MaterialCatalog::empty();
+    for row in rows {
+        catalog
+            .insert(row.name.clone(), row.properties())
+            .map_err(|err| TableError::parse(None, err.to_string()))?;
+    }
+    Ok(catalog)
+}
+
+/// Format a catalogue as a material table
+pub fn format_materials(
+    catalog: &MaterialCatalog,
+    format: TableFormat,
+) -> Result<String, TableError> {
+    let rows: Vec<MaterialRecord> = catalog
+        .iter()
+        .map(|(name, p)| MaterialRecord {
+            name: name.to_string(),
+            frequency: p.frequency,
+            alpha_affinity: p.alpha_affinity,
+            conductivity: p.conductivity,
+        })
+        .collect();
+    format_rows(rows, format, |material| MaterialTable { material })
+}
+
+/// Parse a frequency table
+pub fn parse_frequencies(
+    text: &str,
+    format: TableFormat,
+) -> Result<Vec<FrequencyRecord>, TableError> {
+    parse_rows(text, format, |table: FrequencyTable| table.frequency)
+}
+
+/// Format frequency records as a table, e.g. from `known_frequencies()`
+pub fn format_frequencies(
+    records: &[FrequencyRecord],
+    format: TableFormat,
+) -> Result<String, TableError> {
+    format_rows(records.to_vec(), format, |frequency| FrequencyTable {
+        frequency,
+    })
+}
+
+fn format_of(path: &Path) -> Result<TableFormat, TableError> {
+    TableFormat::from_path(path)
+        .ok_or_else(|| TableError::new(TableErrorKind::UnknownFormat(path.to_path_buf())))
+}
+
+fn read_table<T>(
+    path: &Path,
+    parse: impl FnOnce(&str, TableFormat) -> Result<T, TableError>,
+) -> Result<T, TableError> {
+    let attempt = || {
+        let format = format_of(path)?;
+        parse(&fs::read_to_string(path)?, format)
+    };
+    attempt().map_err(|err| err.in_file(path))
+}
+
+fn write_table(
+    path: &Path,
+    format: impl FnOnce(TableFormat) -> Result<String, TableError>,
+) -> Result<(), TableError> {
+    let attempt = || {
+        let text = format(format_of(path)?)?;
+        fs::write(path, text).map_err(TableError::from)
+    };
+    attempt().map_err(|err| err.in_file(path))
+}
+
+/// Load a material table; the format is chosen by extension (.toml, .json, .csv)
+pub fn load_materials(path: impl AsRef<Path>) -> Result<MaterialCatalog, TableError> {
+    read_table(path.as_ref(), parse_materials)
+}
+
+/// Save a catalogue as a material table; the format is chosen by extension
+pub fn save_materials(path: impl AsRef<Path>, catalog: &MaterialCatalog) -> Result<(), TableError> {
+    write_table(path.as_ref(), |format| format_materials(catalog, format))
+}
+
+/// Load a frequency table; the format is chosen by extension
+pub fn load_frequencies(path: impl AsRef<Path>) -> Result<Vec<FrequencyRecord>, TableError> {
+    read_table(path.as_ref(), parse_frequencies)
+}
+
+/// Save frequency records as a table; the format is chosen by extension
+pub fn save_frequencies(
+    path: impl AsRef<Path>,
+    records: &[FrequencyRecord],
+) -> Result<(), TableError> {
+    write_table(path.as_ref(), |format| format_frequencies(records, format))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::known_frequencies;
+
+    /// Formats compiled into this build
+    fn enabled_formats() -> Vec<TableFormat> {
+        [TableFormat::Toml, TableFormat::Json, TableFormat::Csv]
+            .into_iter()
+            .filter(|f| match f {
+                TableFormat::Toml => cfg!(feature = "toml"),
+                TableFormat::Json => cfg!(feature = "json"),
+                TableFormat::Csv => cfg!(feature = "csv"),
+            })
+            .collect()
+    }
+
+    /// Rows of the README's material table: (name, frequency, affinity, conductivity).
+    /// Kept here because the README lies outside the packaged crate.
+    const README_MATERIALS: [(&str, f64, f64, f64); 8] = [
+        ("Quartz", 32768.0, 0.9, 0.3),
+        ("Gold", 24576.0, 0.95, 0.95),
+        ("Silver", 20480.0, 0.85, 0.9),
+        ("Copper", 16384.0, 0.8, 0.85),
+        ("Iron", 12288.0, 0.6, 0.5),
+        ("Obsidian", 8192.0, 0.7, 0.1),
+        ("Granite", 4096.0, 0.5, 0.05),
+        ("Limestone", 2048.0, 0.4, 0.02),
+    ];
+
+    #[test]
+    fn test_builtin_roundtrip_matches_readme() {
+        for format in enabled_formats() {
+            let text = format_materials(&MaterialCatalog::new(), format).unwrap();
+            let catalog = parse_materials(&text, format).unwrap();
+            assert_eq!(catalog, MaterialCatalog::new());
+
+            let rows: Vec<(&str, f64, f64, f64)> = catalog
+                .iter()
+                .map(|(n, p)| (n, p.frequency, p.alpha_affinity, p.conductivity))
+                .collect();
+            assert_eq!(rows, README_MATERIALS);
+        }
+    }
+
+    #[test]
+    fn test_frequency_roundtrip() {
+        let records: Vec<FrequencyRecord> = known_frequencies()
+            .iter()
+            .map(FrequencyRecord::from)
+            .collect();
+        for format in enabled_formats() {
+            let text = format_frequencies(&records, format).unwrap();
+            assert_eq!(parse_frequencies(&text, format).unwrap(), records);
+        }
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_csv_errors_report_line() {
+        let csv = "name,frequency,alpha_affinity,conductivity\n\
+                   Amethyst,28672,0.88,0.2\n\
+                   Brass,9000,1.5,0.6\n";
+        let err = parse_materials(csv, TableFormat::Csv).unwrap_err();
+        assert_eq!(err.line, Some(3));
+        assert!(err.to_string().contains("alpha affinity 1.5"));
+
+        let err = parse_materials("name,frequency\nGold,abc\n", TableFormat::Csv).unwrap_err();
+        assert_eq!(err.line, Some(2));
+    }
+
+    #[cfg(all(feature = "json", feature = "toml"))]
+    #[test]
+    fn test_json_and_toml_errors_report_line() {
+        let json = "[\n  {\"name\": \"Brass\", \"frequency\": 9000.0,\n   \"alpha_affinity\": \"high\", \"conductivity\": 0.5}\n]";
+        let err = parse_materials(json, TableFormat::Json).unwrap_err();
+        assert_eq!(err.line, Some(3));
+        assert!(!err.to_string().contains("column"));
+
+        // Range checks run once the row is complete, so they point at its end
+        let json = "[{\"name\": \"Brass\", \"frequency\": -1.0, \"alpha_affinity\": 0.5, \"conductivity\": 0.5}]";
+        let err = parse_materials(json, TableFormat::Json).unwrap_err();
+        assert_eq!(err.line, Some(1));
+        assert!(err.to_string().contains("frequency -1"));
+        let json = "[{\"name\": \" \", \"frequency\": 7.83, \"category\": \"schumann\"}]";
+        let err = parse_frequencies(json, TableFormat::Json).unwrap_err();
+        assert!(err.to_string().contains("frequency name is empty"));
+
+        let toml = "[[material]]\nname = \"Brass\"\nfrequency = 9000.0\nalpha_affinity = \"high\"\nconductivity = 0.5\n";
+        let err = parse_materials(toml, TableFormat::Toml).unwrap_err();
+        assert_eq!(err.line, Some(4));
+    }
+
+    #[test]
+    fn test_files() {
+        let dir = std::env::temp_dir().join(format!("ra-constants-tables-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let err = load_materials(dir.join("materials.xml")).unwrap_err();
+        assert!(matches!(err.kind, TableErrorKind::UnknownFormat(_)));
+        assert_eq!(err.path, Some(dir.join("materials.xml")));
+
+        for format in enabled_formats() {
+            let path = dir.join(format!("materials.{}", format.feature()));
+            save_materials(&path, &MaterialCatalog::new()).unwrap();
+            assert_eq!(load_materials(&path).unwrap(), MaterialCatalog::new());
+
+            let err =
+                load_materials(dir.join(format!("missing.{}", format.feature()))).unwrap_err();
+            assert!(matches!(err.kind, TableErrorKind::Io(_)));
+            assert!(err.to_string().contains("missing"));
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
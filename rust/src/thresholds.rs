@@ -3,6 +3,8 @@
 //! (c) 2025 Anywave Creations
 //! MIT License
 
+use crate::phi::{PHI_NEG1, PHI_NEG2, PHI_NEG3, PHI_NEG4};
+
 /// High coherence threshold (85%)
 pub const HIGH_COHERENCE: f64 = 0.85;
 
@@ -110,6 +112,77 @@ impl CoherenceLevel {
     }
 }
 
+/// Consent state classification on the φ⁻¹/φ⁻²/φ⁻³/φ⁻⁴ threshold cascade
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsentState {
+    Full,
+    Diminished,
+    Suspended,
+    EmergencyOverride,
+    Critical,
+}
+
+impl ConsentState {
+    /// Get the band definition for this state
+    pub const fn band(&self) -> CoherenceBand {
+        match self {
+            Self::Full => CoherenceBand::new(PHI_NEG1, 1.01, "full"),
+            Self::Diminished => CoherenceBand::new(PHI_NEG2, PHI_NEG1, "diminished"),
+            Self::Suspended => CoherenceBand::new(PHI_NEG3, PHI_NEG2, "suspended"),
+            Self::EmergencyOverride => CoherenceBand::new(PHI_NEG4, PHI_NEG3, "emergency_override"),
+            Self::Critical => CoherenceBand::new(0.0, PHI_NEG4, "critical"),
+        }
+    }
+
+    /// Get lower bound of this band
+    pub const fn lower(&self) -> f64 {
+        self.band().lower
+    }
+
+    /// Get upper bound of this band
+    pub const fn upper(&self) -> f64 {
+        self.band().upper
+    }
+
+    /// Classify a coherence value into a consent state
+    ///
+    /// # Arguments
+    /// * `value` - Coherence value (0-1)
+    ///
+    /// # Returns
+    /// The appropriate ConsentState
+    ///
+    /// # Panics
+    /// Panics if value is not between 0 and 1
+    pub fn classify(value: f64) -> Self {
+        if !(0.0..=1.0).contains(&value) {
+            panic!("Coherence must be between 0 and 1");
+        }
+
+        let states = [
+            Self::Full,
+            Self::Diminished,
+            Self::Suspended,
+            Self::EmergencyOverride,
+            Self::Critical,
+        ];
+
+        for state in states {
+            if state.band().contains(value) {
+                return state;
+            }
+        }
+
+        // Handle edge case of exactly 1.0
+        Self::Full
+    }
+
+    /// Check if a value falls within this state's band
+    pub fn contains(&self, value: f64) -> bool {
+        self.band().contains(value)
+    }
+}
+
 /// Normalize a value to the 0-1 coherence range.
 ///
 /// # Arguments
@@ -181,6 +254,22 @@ mod tests {
         assert_eq!(CoherenceLevel::classify(0.05), CoherenceLevel::Minimal);
     }
 
+    #[test]
+    fn test_consent_state_classify() {
+        assert_eq!(ConsentState::classify(1.0), ConsentState::Full);
+        assert_eq!(ConsentState::classify(PHI_NEG1), ConsentState::Full);
+        assert_eq!(ConsentState::classify(0.5), ConsentState::Diminished);
+        assert_eq!(ConsentState::classify(0.3), ConsentState::Suspended);
+        assert_eq!(ConsentState::classify(0.2), ConsentState::EmergencyOverride);
+        assert_eq!(ConsentState::classify(0.1), ConsentState::Critical);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_consent_state_out_of_range() {
+        ConsentState::classify(1.5);
+    }
+
     #[test]
     fn test_normalize_coherence() {
         assert!((normalize_coherence(50.0, 0.0, 100.0) - 0.5).abs() < 1e-10);
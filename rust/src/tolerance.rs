@@ -0,0 +1,122 @@
+//! Tolerance model - one definition of "close enough" for ratio and frequency checks.
+//!
+//! (c) 2025 Anywave Creations
+//! MIT License
+
+/// How far a measured value may deviate from its target.
+///
+/// The float limits are exclusive, as in the crate's original `< tolerance`
+/// checks, so a limit of 0.0 matches nothing; `Ulps` counts inclusively.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    /// Exclusive bound on the absolute difference |actual - target|
+    Absolute(f64),
+    /// Exclusive bound on the difference relative to the target, e.g. 0.005 for 0.5%
+    Relative(f64),
+    /// Exclusive bound on the pitch distance in cents (100 cents = 1 semitone);
+    /// both values must be positive
+    Cents(f64),
+    /// Maximum number of representable f64 values between actual and target (0 = equal)
+    Ulps(u64),
+}
+
+impl Tolerance {
+    /// Measure the deviation of a value from its target in this tolerance's units.
+    ///
+    /// # Arguments
+    /// * `actual` - Measured value
+    /// * `target` - Expected value
+    ///
+    /// # Returns
+    /// Non-negative deviation, or infinity if the values cannot be compared
+    /// (NaN input, or non-positive values for `Cents`)
+    pub fn deviation(&self, actual: f64, target: f64) -> f64 {
+        if actual.is_nan() || target.is_nan() {
+            return f64::INFINITY;
+        }
+
+        match self {
+            Self::Absolute(_) => (actual - target).abs(),
+            Self::Relative(_) if actual == target => 0.0,
+            Self::Relative(_) => (actual - target).abs() / target.abs(),
+            Self::Cents(_) => {
+                if actual <= 0.0 || target <= 0.0 {
+                    return f64::INFINITY;
+                }
+                (1200.0 * (actual / target).log2()).abs()
+            }
+            Self::Ulps(_) => ulps_between(actual, target) as f64,
+        }
+    }
+
+    /// Check if a value lies within this tolerance of its target
+    pub fn matches(&self, actual: f64, target: f64) -> bool {
+        let deviation = self.deviation(actual, target);
+        match *self {
+            Self::Absolute(limit) | Self::Relative(limit) | Self::Cents(limit) => deviation < limit,
+            Self::Ulps(limit) => deviation.is_finite() && ulps_between(actual, target) <= limit,
+        }
+    }
+}
+
+/// A bare f64 is an absolute tolerance, matching the crate's original checks
+impl From<f64> for Tolerance {
+    fn from(value: f64) -> Self {
+        Self::Absolute(value)
+    }
+}
+
+/// Map an f64 onto a monotonic integer scale so that adjacent floats differ by 1.
+fn ordered_bits(value: f64) -> i64 {
+    let bits = value.to_bits() as i64;
+    if bits < 0 {
+        i64::MIN - bits
+    } else {
+        bits
+    }
+}
+
+/// Number of representable f64 values between two floats
+fn ulps_between(a: f64, b: f64) -> u64 {
+    ordered_bits(a).abs_diff(ordered_bits(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_absolute_and_relative() {
+        assert!(Tolerance::Absolute(0.1).matches(1.05, 1.0));
+        assert!(!Tolerance::Absolute(0.01).matches(1.05, 1.0));
+        assert!(Tolerance::Relative(0.005).matches(442.0, 440.0));
+        assert!(!Tolerance::Relative(0.001).matches(442.0, 440.0));
+        assert_eq!(Tolerance::from(0.5), Tolerance::Absolute(0.5));
+
+        // Limits are exclusive, like the original `< tolerance` checks
+        assert!(!Tolerance::Absolute(0.0).matches(1.0, 1.0));
+        assert!(!Tolerance::Absolute(0.5).matches(1.5, 1.0));
+        assert!(!crate::phi::is_phi_ratio(1.0, crate::phi::PHI, 0.0));
+    }
+
+    #[test]
+    fn test_cents() {
+        // A440 vs A432 is about 31.8 cents
+        assert!(Tolerance::Cents(32.0).matches(440.0, 432.0));
+        assert!(!Tolerance::Cents(5.0).matches(440.0, 432.0));
+        assert!(Tolerance::Cents(1e-9).matches(880.0, 880.0));
+        assert!(!Tolerance::Cents(100.0).matches(-1.0, 440.0));
+    }
+
+    #[test]
+    fn test_ulps() {
+        let x = 1.0_f64;
+        let next = f64::from_bits(x.to_bits() + 1);
+        assert!(Tolerance::Ulps(0).matches(x, x));
+        assert!(!Tolerance::Ulps(0).matches(next, x));
+        assert!(Tolerance::Ulps(1).matches(next, x));
+        assert!(Tolerance::Ulps(2).matches(f64::from_bits(1), -f64::from_bits(1)));
+        assert!(Tolerance::Ulps(1).matches(0.0, -0.0));
+        assert!(!Tolerance::Ulps(u64::MAX).matches(f64::NAN, 1.0));
+    }
+}
@@ -0,0 +1,325 @@
+//! Tuning systems - equal temperament, just intonation, Pythagorean and meantone.
+//!
+//! (c) 2025 Anywave Creations
+//! MIT License
+
+use crate::frequencies::{cents_difference, A432, A440};
+
+/// A mapping between scale degrees and frequencies.
+///
+/// Degree 0 is the tuning's reference note; degrees repeat every period
+/// (usually the octave) and may be negative.
+pub trait Tuning {
+    /// Frequency of a scale degree in Hz
+    fn frequency(&self, degree: i32) -> f64;
+
+    /// Number of degrees per period
+    fn steps_per_period(&self) -> usize;
+
+    /// Period as a frequency ratio (2.0 for octave-repeating tunings)
+    fn period(&self) -> f64 {
+        2.0
+    }
+
+    /// Find the degree nearest to a frequency.
+    ///
+    /// # Arguments
+    /// * `frequency` - Frequency in Hz (must be positive and finite)
+    ///
+    /// # Returns
+    /// (degree, cents offset of the frequency from that degree)
+    ///
+    /// # Panics
+    /// Panics if the frequency is not positive and finite, or if the degrees
+    /// around it do not fit in an i32
+    fn nearest_degree(&self, frequency: f64) -> (i32, f64) {
+        if !(frequency > 0.0 && frequency.is_finite()) {
+            panic!("Frequency must be positive and finite");
+        }
+
+        let periods =
+            ((frequency.log2() - self.frequency(0).log2()) / self.period().log2()).floor();
+        let range = i32::try_from(self.steps_per_period())
+            .ok()
+            .zip(degree_from_f64(periods))
+            .and_then(|(steps, periods)| {
+                let base = periods.checked_mul(steps)?;
+                Some(base.checked_sub(1)?..=base.checked_add(steps)?)
+            });
+        let Some(degrees) = range else {
+            panic!("Degree must fit in an i32");
+        };
+
+        degrees
+            .map(|degree| (degree, cents_difference(self.frequency(degree), frequency)))
+            .min_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+            .unwrap_or((0, 0.0))
+    }
+
+    /// Cents deviation of a degree from the nearest 12-TET pitch on the same reference
+    fn cents_from_12tet(&self, degree: i32) -> f64 {
+        let cents = cents_difference(self.frequency(0), self.frequency(degree));
+        cents - 100.0 * (cents / 100.0).round()
+    }
+}
+
+/// Convert a whole number of steps to a degree, if it fits in an i32
+fn degree_from_f64(steps: f64) -> Option<i32> {
+    (i32::MIN as f64..=i32::MAX as f64)
+        .contains(&steps)
+        .then_some(steps as i32)
+}
+
+/// N-tone equal division of the octave (N-EDO)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EqualTemperament {
+    /// Number of equal steps per octave
+    pub divisions: u32,
+    /// Frequency of degree 0 in Hz
+    pub reference: f64,
+}
+
+impl EqualTemperament {
+    /// Create an N-EDO tuning on a reference frequency
+    ///
+    /// # Panics
+    /// Panics if divisions is 0
+    pub const fn new(divisions: u32, reference: f64) -> Self {
+        if divisions == 0 {
+            panic!("divisions must be >= 1");
+        }
+        Self {
+            divisions,
+            reference,
+        }
+    }
+
+    /// 12-TET with degree 0 at a reference frequency
+    pub const fn twelve_tet(reference: f64) -> Self {
+        Self::new(12, reference)
+    }
+
+    /// 12-TET with degree 0 at A432
+    pub const fn a432() -> Self {
+        Self::twelve_tet(A432)
+    }
+
+    /// 12-TET with degree 0 at A440
+    pub const fn a440() -> Self {
+        Self::twelve_tet(A440)
+    }
+
+    /// Size of one step in cents
+    pub fn step_cents(&self) -> f64 {
+        1200.0 / self.divisions as f64
+    }
+}
+
+impl Tuning for EqualTemperament {
+    fn frequency(&self, degree: i32) -> f64 {
+        self.reference * 2.0_f64.powf(degree as f64 / self.divisions as f64)
+    }
+
+    fn steps_per_period(&self) -> usize {
+        self.divisions as usize
+    }
+
+    /// # Panics
+    /// Panics if the frequency is not positive and finite, or if the nearest
+    /// degree does not fit in an i32
+    fn nearest_degree(&self, frequency: f64) -> (i32, f64) {
+        if !(frequency > 0.0 && frequency.is_finite()) {
+            panic!("Frequency must be positive and finite");
+        }
+
+        let steps = self.divisions as f64 * (frequency.log2() - self.reference.log2());
+        let Some(degree) = degree_from_f64(steps.round()) else {
+            panic!("Degree must fit in an i32");
+        };
+        (degree, cents_difference(self.frequency(degree), frequency))
+    }
+}
+
+/// A tuning defined by frequency ratios within one period
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaleTuning {
+    /// Frequency of degree 0 in Hz
+    pub tonic: f64,
+    ratios: Vec<f64>,
+    period: f64,
+}
+
+impl ScaleTuning {
+    /// Create a tuning from the ratios of degrees 1..n to the tonic, and the period.
+    ///
+    /// # Arguments
+    /// * `tonic` - Frequency of degree 0 in Hz
+    /// * `ratios` - Ratios of the remaining degrees, ascending and below the period
+    /// * `period` - Ratio at which the scale repeats (2.0 for the octave)
+    ///
+    /// # Panics
+    /// Panics if the ratios are not ascending between 1 and a finite period
+    /// (NaN never ascends)
+    pub fn new(tonic: f64, ratios: &[f64], period: f64) -> Self {
+        let mut all = Vec::with_capacity(ratios.len() + 1);
+        all.push(1.0);
+        all.extend_from_slice(ratios);
+
+        let ascending = all.windows(2).all(|w| w[0] < w[1]) && all[all.len() - 1] < period;
+        if !(ascending && period.is_finite()) {
+            panic!("Ratios must ascend between 1 and the period");
+        }
+
+        Self {
+            tonic,
+            ratios: all,
+            period,
+        }
+    }
+
+    /// 5-limit just intonation chromatic scale
+    pub fn just_intonation(tonic: f64) -> Self {
+        Self::new(
+            tonic,
+            &[
+                16.0 / 15.0,
+                9.0 / 8.0,
+                6.0 / 5.0,
+                5.0 / 4.0,
+                4.0 / 3.0,
+                45.0 / 32.0,
+                3.0 / 2.0,
+                8.0 / 5.0,
+                5.0 / 3.0,
+                9.0 / 5.0,
+                15.0 / 8.0,
+            ],
+            2.0,
+        )
+    }
+
+    /// Pythagorean chromatic scale from pure 3:2 fifths (Eb to G#)
+    pub fn pythagorean(tonic: f64) -> Self {
+        Self::chain_of_fifths(tonic, 1.5)
+    }
+
+    /// Quarter-comma meantone chromatic scale with pure 5:4 major thirds (Eb to G#)
+    pub fn quarter_comma_meantone(tonic: f64) -> Self {
+        Self::chain_of_fifths(tonic, 5.0_f64.powf(0.25))
+    }
+
+    /// Build a 12-note scale from a chain of fifths running from -3 to +8
+    fn chain_of_fifths(tonic: f64, fifth: f64) -> Self {
+        let mut ratios = [0.0; 11];
+        for k in -3..=8_i32 {
+            let semitone = (7 * k).rem_euclid(12) as usize;
+            if semitone == 0 {
+                continue;
+            }
+            let ratio = fifth.powi(k);
+            ratios[semitone - 1] = ratio / 2.0_f64.powi(ratio.log2().floor() as i32);
+        }
+        Self::new(tonic, &ratios, 2.0)
+    }
+
+    /// Move the tonic so that a degree sounds at a given frequency.
+    ///
+    /// For example, `ScaleTuning::just_intonation(1.0).anchored(9, A432)` is
+    /// just intonation on C with A at 432 Hz.
+    pub fn anchored(mut self, degree: i32, frequency: f64) -> Self {
+        self.tonic *= frequency / self.frequency(degree);
+        self
+    }
+
+    /// Ratios of each degree in one period to the tonic, starting with 1.0
+    pub fn ratios(&self) -> &[f64] {
+        &self.ratios
+    }
+}
+
+impl Tuning for ScaleTuning {
+    fn frequency(&self, degree: i32) -> f64 {
+        let steps = self.ratios.len() as i32;
+        let ratio = self.ratios[degree.rem_euclid(steps) as usize];
+        self.tonic * ratio * self.period.powi(degree.div_euclid(steps))
+    }
+
+    fn steps_per_period(&self) -> usize {
+        self.ratios.len()
+    }
+
+    fn period(&self) -> f64 {
+        self.period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equal_temperament() {
+        let tet = EqualTemperament::a440();
+        assert!((tet.frequency(3) - 523.2511306011972).abs() < 1e-9);
+        assert!((tet.frequency(-12) - 220.0).abs() < 1e-12);
+        assert_eq!(tet.nearest_degree(446.0).0, 0);
+        assert_eq!(tet.cents_from_12tet(7), 0.0);
+
+        let edo19 = EqualTemperament::new(19, A432);
+        assert!((edo19.frequency(19) - 864.0).abs() < 1e-9);
+        let (degree, offset) = edo19.nearest_degree(edo19.frequency(-25) * 1.001);
+        assert_eq!(degree, -25);
+        assert!((offset - 1.7297).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_just_and_pythagorean() {
+        let just = ScaleTuning::just_intonation(264.0);
+        assert_eq!(just.frequency(7), 396.0);
+        assert!((just.frequency(12 + 9) - 880.0).abs() < 1e-9);
+        assert!((just.cents_from_12tet(4) + 13.686).abs() < 1e-3);
+
+        let pythagorean = ScaleTuning::pythagorean(1.0);
+        assert!((pythagorean.frequency(4) - 81.0 / 64.0).abs() < 1e-12);
+        assert!((pythagorean.frequency(6) - 729.0 / 512.0).abs() < 1e-12);
+        assert!((pythagorean.cents_from_12tet(4) - 7.820).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_meantone_and_anchor() {
+        let meantone = ScaleTuning::quarter_comma_meantone(1.0);
+        assert!((meantone.frequency(4) - 1.25).abs() < 1e-12);
+        assert!((meantone.cents_from_12tet(7) + 3.422).abs() < 1e-3);
+
+        let anchored = ScaleTuning::just_intonation(1.0).anchored(9, A432);
+        assert!((anchored.frequency(9) - A432).abs() < 1e-12);
+        assert!((anchored.tonic - 259.2).abs() < 1e-9);
+        let (degree, offset) = anchored.nearest_degree(A432 * 2.0);
+        assert_eq!(degree, 21);
+        assert!(offset.abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scale_tuning_rejects_unsorted_ratios() {
+        ScaleTuning::new(1.0, &[1.5, 1.25], 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Ratios must ascend between 1 and the period")]
+    fn test_scale_tuning_rejects_nan_ratios() {
+        ScaleTuning::new(1.0, &[1.25, f64::NAN], 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Frequency must be positive and finite")]
+    fn test_nearest_degree_rejects_zero() {
+        ScaleTuning::just_intonation(A432).nearest_degree(0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Degree must fit in an i32")]
+    fn test_nearest_degree_out_of_range() {
+        EqualTemperament::new(u32::MAX, 1.0).nearest_degree(f64::MAX);
+    }
+}
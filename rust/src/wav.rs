@@ -0,0 +1,455 @@
+//! WAV files - dependency-free RIFF/WAVE reading and writing.
+//!
+//! (c) 2025 Anywave Creations
+//! MIT License
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+/// WAVE format tags
+const FORMAT_PCM: u16 = 1;
+const FORMAT_FLOAT: u16 = 3;
+const FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+/// Sample encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SampleFormat {
+    /// 16-bit signed integer PCM
+    Pcm16,
+    /// 24-bit signed integer PCM
+    Pcm24,
+    /// 32-bit IEEE float
+    Float32,
+}
+
+impl SampleFormat {
+    /// Bits per sample
+    pub const fn bits(&self) -> u16 {
+        match self {
+            Self::Pcm16 => 16,
+            Self::Pcm24 => 24,
+            Self::Float32 => 32,
+        }
+    }
+
+    /// Bytes per sample
+    pub const fn bytes(&self) -> usize {
+        self.bits() as usize / 8
+    }
+
+    const fn tag(&self) -> u16 {
+        match self {
+            Self::Pcm16 | Self::Pcm24 => FORMAT_PCM,
+            Self::Float32 => FORMAT_FLOAT,
+        }
+    }
+
+    /// Largest positive integer sample, used to scale PCM to -1..1
+    const fn full_scale(&self) -> f32 {
+        match self {
+            Self::Pcm16 => 32_767.0,
+            Self::Pcm24 => 8_388_607.0,
+            Self::Float32 => 1.0,
+        }
+    }
+}
+
+/// Layout of the samples in a WAV file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WavSpec {
+    /// Samples per second per channel
+    pub sample_rate: u32,
+    /// 1 (mono) or 2 (stereo)
+    pub channels: u16,
+    /// Encoding of each sample
+    pub format: SampleFormat,
+}
+
+impl WavSpec {
+    /// Create a spec
+    pub const fn new(sample_rate: u32, channels: u16, format: SampleFormat) -> Self {
+        Self {
+            sample_rate,
+            channels,
+            format,
+        }
+    }
+
+    /// Mono spec
+    pub const fn mono(sample_rate: u32, format: SampleFormat) -> Self {
+        Self::new(sample_rate, 1, format)
+    }
+
+    /// Stereo spec (samples interleaved left, right)
+    pub const fn stereo(sample_rate: u32, format: SampleFormat) -> Self {
+        Self::new(sample_rate, 2, format)
+    }
+
+    const fn block_align(&self) -> usize {
+        self.channels as usize * self.format.bytes()
+    }
+}
+
+/// Error reading or writing a WAV file
+#[derive(Debug)]
+pub enum WavError {
+    /// Underlying I/O failure
+    Io(io::Error),
+    /// Missing "RIFF"/"WAVE" header
+    NotWave,
+    /// Required chunk ("fmt " or "data") not found
+    MissingChunk(&'static str),
+    /// Format tag and bit depth combination not supported
+    UnsupportedFormat { tag: u16, bits: u16 },
+    /// Only mono and stereo are supported
+    UnsupportedChannels(u16),
+    /// A chunk runs past the end of the file
+    Truncated,
+    /// Sample count is not a whole number of frames
+    IncompleteFrame { samples: usize, channels: u16 },
+    /// Byte rate or data size does not fit in the 32-bit header fields
+    TooLarge,
+}
+
+impl fmt::Display for WavError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "I/O error: {}", err),
+            Self::NotWave => write!(f, "not a RIFF/WAVE file"),
+            Self::MissingChunk(id) => write!(f, "missing '{}' chunk", id),
+            Self::UnsupportedFormat { tag, bits } => {
+                write!(f, "unsupported format tag {} with {} bits", tag, bits)
+            }
+            Self::UnsupportedChannels(n) => write!(f, "unsupported channel count {}", n),
+            Self::Truncated => write!(f, "file is truncated"),
+            Self::IncompleteFrame { samples, channels } => write!(
+                f,
+                "{} samples do not divide into {} channels",
+                samples, channels
+            ),
+            Self::TooLarge => write!(f, "sample rate or data size exceeds the WAV header limits"),
+        }
+    }
+}
+
+impl std::error::Error for WavError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for WavError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Write interleaved samples as a WAV stream.
+///
+/// PCM formats clamp samples to -1..1.
+///
+/// # Arguments
+/// * `writer` - Destination
+/// * `spec` - Sample rate, channel count and format
+/// * `samples` - Interleaved samples in the range -1 to 1 (whole frames only)
+pub fn write_wav<W: Write>(mut writer: W, spec: WavSpec, samples: &[f32]) -> Result<(), WavError> {
+    if !(1..=2).contains(&spec.channels) {
+        return Err(WavError::UnsupportedChannels(spec.channels));
+    }
+    if !samples.len().is_multiple_of(spec.channels as usize) {
+        return Err(WavError::IncompleteFrame {
+            samples: samples.len(),
+            channels: spec.channels,
+        });
+    }
+
+    let data_len = samples.len() * spec.format.bytes();
+    let riff_len = 4 + (8 + 16) + (8 + data_len) + data_len % 2;
+    let riff_len = u32::try_from(riff_len).map_err(|_| WavError::TooLarge)?;
+    let byte_rate = spec
+        .sample_rate
+        .checked_mul(spec.block_align() as u32)
+        .ok_or(WavError::TooLarge)?;
+
+    let mut header = Vec::with_capacity(44);
+    header.extend_from_slice(b"RIFF");
+    header.extend_from_slice(&riff_len.to_le_bytes());
+    header.extend_from_slice(b"WAVEfmt ");
+    header.extend_from_slice(&16_u32.to_le_bytes());
+    header.extend_from_slice(&spec.format.tag().to_le_bytes());
+    header.extend_from_slice(&spec.channels.to_le_bytes());
+    header.extend_from_slice(&spec.sample_rate.to_le_bytes());
+    header.extend_from_slice(&byte_rate.to_le_bytes());
+    header.extend_from_slice(&(spec.block_align() as u16).to_le_bytes());
+    header.extend_from_slice(&spec.format.bits().to_le_bytes());
+    header.extend_from_slice(b"data");
+    header.extend_from_slice(&(data_len as u32).to_le_bytes());
+    writer.write_all(&header)?;
+
+    let mut data = Vec::with_capacity(data_len + 1);
+    let scale = spec.format.full_scale();
+    for &sample in samples {
+        match spec.format {
+            SampleFormat::Pcm16 => {
+                let value = (sample.clamp(-1.0, 1.0) * scale).round() as i16;
+                data.extend_from_slice(&value.to_le_bytes());
+            }
+            SampleFormat::Pcm24 => {
+                let value = (sample.clamp(-1.0, 1.0) * scale).round() as i32;
+                data.extend_from_slice(&value.to_le_bytes()[..3]);
+            }
+            SampleFormat::Float32 => data.extend_from_slice(&sample.to_le_bytes()),
+        }
+    }
+    if data_len % 2 == 1 {
+        data.push(0);
+    }
+    writer.write_all(&data)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Read a WAV stream.
+///
+/// Accepts 16/24-bit PCM and 32-bit float, mono or stereo, including
+/// WAVE_FORMAT_EXTENSIBLE headers. Unknown chunks are skipped.
+///
+/// # Returns
+/// The spec and interleaved samples scaled to -1..1
+pub fn read_wav<R: Read>(mut reader: R) -> Result<(WavSpec, Vec<f32>), WavError> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return Err(WavError::NotWave);
+    }
+
+    let mut spec = None;
+    let mut pos = 12;
+    while pos + 8 <= bytes.len() {
+        let id = &bytes[pos..pos + 4];
+        let len = u32_at(&bytes, pos + 4) as usize;
+        let body = bytes
+            .get(pos + 8..pos + 8 + len)
+            .ok_or(WavError::Truncated)?;
+
+        match id {
+            b"fmt " => spec = Some(parse_format(body)?),
+            b"data" => {
+                let spec = spec.ok_or(WavError::MissingChunk("fmt "))?;
+                return Ok((spec, decode_samples(body, spec)));
+            }
+            _ => {}
+        }
+        // Chunks are padded to an even length
+        pos += 8 + len + len % 2;
+    }
+
+    Err(WavError::MissingChunk(if spec.is_some() {
+        "data"
+    } else {
+        "fmt "
+    }))
+}
+
+/// Write interleaved samples to a WAV file
+pub fn save_wav(path: impl AsRef<Path>, spec: WavSpec, samples: &[f32]) -> Result<(), WavError> {
+    write_wav(BufWriter::new(File::create(path)?), spec, samples)
+}
+
+/// Read a WAV file into its spec and interleaved samples
+pub fn load_wav(path: impl AsRef<Path>) -> Result<(WavSpec, Vec<f32>), WavError> {
+    read_wav(BufReader::new(File::open(path)?))
+}
+
+fn u16_at(bytes: &[u8], pos: usize) -> u16 {
+    u16::from_le_bytes([bytes[pos], bytes[pos + 1]])
+}
+
+fn u32_at(bytes: &[u8], pos: usize) -> u32 {
+    u32::from_le_bytes([bytes[pos], bytes[pos + 1], bytes[pos + 2], bytes[pos + 3]])
+}
+
+fn parse_format(body: &[u8]) -> Result<WavSpec, WavError> {
+    if body.len() < 16 {
+        return Err(WavError::Truncated);
+    }
+
+    let mut tag = u16_at(body, 0);
+    let channels = u16_at(body, 2);
+    let sample_rate = u32_at(body, 4);
+    let bits = u16_at(body, 14);
+
+    // The real format tag of an extensible header opens its sub-format GUID
+    if tag == FORMAT_EXTENSIBLE {
+        if body.len() < 26 {
+            return Err(WavError::Truncated);
+        }
+        tag = u16_at(body, 24);
+    }
+
+    let format = match (tag, bits) {
+        (FORMAT_PCM, 16) => SampleFormat::Pcm16,
+        (FORMAT_PCM, 24) => SampleFormat::Pcm24,
+        (FORMAT_FLOAT, 32) => SampleFormat::Float32,
+        _ => return Err(WavError::UnsupportedFormat { tag, bits }),
+    };
+    if !(1..=2).contains(&channels) {
+        return Err(WavError::UnsupportedChannels(channels));
+    }
+
+    Ok(WavSpec::new(sample_rate, channels, format))
+}
+
+fn decode_samples(data: &[u8], spec: WavSpec) -> Vec<f32> {
+    let scale = spec.format.full_scale();
+    // Drop any partial frame at the end
+    let frames = data.len() / spec.block_align();
+    data[..frames * spec.block_align()]
+        .chunks_exact(spec.format.bytes())
+        .map(|b| match spec.format {
+            SampleFormat::Pcm16 => (i16::from_le_bytes([b[0], b[1]]) as f32 / scale).max(-1.0),
+            // Shift the 24-bit value into the top of an i32 to sign-extend it
+            SampleFormat::Pcm24 => {
+                ((i32::from_le_bytes([0, b[0], b[1], b[2]]) >> 8) as f32 / scale).max(-1.0)
+            }
+            SampleFormat::Float32 => f32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::synth::Synth;
+
+    fn encode(spec: WavSpec, samples: &[f32]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        write_wav(&mut bytes, spec, samples).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_header() {
+        let bytes = encode(WavSpec::mono(44_100, SampleFormat::Pcm16), &[0.0, 0.5]);
+        assert_eq!(bytes.len(), 48);
+        assert_eq!(&bytes[0..4], b"RIFF");
+        assert_eq!(u32_at(&bytes, 4), 40);
+        assert_eq!(&bytes[8..16], b"WAVEfmt ");
+        assert_eq!(u32_at(&bytes, 24), 44_100);
+        assert_eq!(u32_at(&bytes, 28), 88_200);
+        assert_eq!(&bytes[36..40], b"data");
+        assert_eq!(u32_at(&bytes, 40), 4);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let tone = Synth::new(8_000)
+            .with_amplitude(0.9)
+            .binaural(432.0, 7.83, 0.1);
+        for format in [
+            SampleFormat::Pcm16,
+            SampleFormat::Pcm24,
+            SampleFormat::Float32,
+        ] {
+            let spec = WavSpec::stereo(8_000, format);
+            let (read_spec, samples) = read_wav(encode(spec, &tone).as_slice()).unwrap();
+            assert_eq!(read_spec, spec);
+            assert_eq!(samples.len(), tone.len());
+
+            let step = 1.0 / format.full_scale();
+            for (a, b) in samples.iter().zip(&tone) {
+                assert!((a - b).abs() <= step);
+            }
+        }
+
+        // Odd-length 24-bit mono data is padded, and clipping clamps
+        let spec = WavSpec::mono(8_000, SampleFormat::Pcm24);
+        let bytes = encode(spec, &[1.5, -1.5, 0.25]);
+        assert_eq!(bytes.len() % 2, 0);
+        let (_, samples) = read_wav(bytes.as_slice()).unwrap();
+        assert_eq!(samples, [1.0, -1.0, 2_097_152.0 / 8_388_607.0]);
+    }
+
+    #[test]
+    fn test_skips_unknown_chunks_and_extensible() {
+        let mut bytes = encode(WavSpec::mono(48_000, SampleFormat::Pcm16), &[0.25; 4]);
+        // Insert an odd-sized LIST chunk (padded) before "data"
+        let list = [b"LIST".as_slice(), &3_u32.to_le_bytes(), b"abc\0"].concat();
+        bytes.splice(36..36, list);
+        let (spec, samples) = read_wav(bytes.as_slice()).unwrap();
+        assert_eq!(spec.sample_rate, 48_000);
+        assert_eq!(samples.len(), 4);
+
+        // Rewrite as WAVE_FORMAT_EXTENSIBLE with a float sub-format
+        let mut ext = encode(WavSpec::mono(48_000, SampleFormat::Float32), &[0.5]);
+        let mut fmt = ext[20..36].to_vec();
+        fmt[0..2].copy_from_slice(&FORMAT_EXTENSIBLE.to_le_bytes());
+        fmt.extend_from_slice(&22_u16.to_le_bytes());
+        fmt.extend_from_slice(&[0; 6]);
+        fmt.extend_from_slice(&FORMAT_FLOAT.to_le_bytes());
+        fmt.extend_from_slice(&[0; 14]);
+        ext.splice(16..36, [&40_u32.to_le_bytes()[..], &fmt].concat());
+        let (spec, samples) = read_wav(ext.as_slice()).unwrap();
+        assert_eq!(spec.format, SampleFormat::Float32);
+        assert_eq!(samples, [0.5]);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(read_wav(&b"RIFX"[..]), Err(WavError::NotWave)));
+
+        let mut eight_bit = encode(WavSpec::mono(8_000, SampleFormat::Pcm16), &[0.0]);
+        eight_bit[34] = 8;
+        assert!(matches!(
+            read_wav(eight_bit.as_slice()),
+            Err(WavError::UnsupportedFormat { tag: 1, bits: 8 })
+        ));
+
+        let mut surround = encode(WavSpec::mono(8_000, SampleFormat::Pcm16), &[0.0]);
+        surround[22] = 6;
+        assert!(matches!(
+            read_wav(surround.as_slice()),
+            Err(WavError::UnsupportedChannels(6))
+        ));
+
+        let full = encode(WavSpec::mono(8_000, SampleFormat::Pcm16), &[0.0; 8]);
+        assert!(matches!(read_wav(&full[..50]), Err(WavError::Truncated)));
+        assert!(matches!(
+            read_wav(&full[..36]),
+            Err(WavError::MissingChunk("data"))
+        ));
+        assert!(matches!(
+            write_wav(
+                Vec::new(),
+                WavSpec::new(8_000, 3, SampleFormat::Pcm16),
+                &[0.0; 3]
+            ),
+            Err(WavError::UnsupportedChannels(3))
+        ));
+        assert!(matches!(
+            write_wav(
+                Vec::new(),
+                WavSpec::stereo(8_000, SampleFormat::Pcm16),
+                &[0.0; 3]
+            ),
+            Err(WavError::IncompleteFrame {
+                samples: 3,
+                channels: 2
+            })
+        ));
+        assert!(matches!(
+            write_wav(
+                Vec::new(),
+                WavSpec::stereo(u32::MAX, SampleFormat::Float32),
+                &[0.0; 2]
+            ),
+            Err(WavError::TooLarge)
+        ));
+    }
+}
@@ -0,0 +1,813 @@
+//! Exact golden-field arithmetic - numbers of the form a + bφ.
+//!
+//! (c) 2025 Anywave Creations
+//! MIT License
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::continued_fraction::gcd;
+use crate::phi::{fibonacci, PHI};
+
+/// Unsigned 256-bit magnitude as (high, low) halves; tuples order
+/// lexicographically, so the derived comparisons are numeric
+type Wide = (u128, u128);
+
+const fn wide(x: u128) -> Wide {
+    (0, x)
+}
+
+/// Full 256-bit product of two u128 values
+const fn wide_mul(x: u128, y: u128) -> Wide {
+    const MASK: u128 = u64::MAX as u128;
+    let (x_hi, x_lo) = (x >> 64, x & MASK);
+    let (y_hi, y_lo) = (y >> 64, y & MASK);
+
+    let low = x_lo * y_lo;
+    let cross_a = x_hi * y_lo;
+    let cross_b = x_lo * y_hi;
+    let (middle, middle_carry) = cross_a.overflowing_add(cross_b);
+    let (lo, low_carry) = low.overflowing_add(middle << 64);
+    let hi = x_hi * y_hi + (middle >> 64) + ((middle_carry as u128) << 64) + low_carry as u128;
+    (hi, lo)
+}
+
+/// x + y; callers keep the sum below 2²⁵⁶
+const fn wide_add(x: Wide, y: Wide) -> Wide {
+    let (lo, carry) = x.1.overflowing_add(y.1);
+    (x.0 + y.0 + carry as u128, lo)
+}
+
+/// x - y; callers ensure x >= y
+const fn wide_sub(x: Wide, y: Wide) -> Wide {
+    let (lo, borrow) = x.1.overflowing_sub(y.1);
+    (x.0 - y.0 - borrow as u128, lo)
+}
+
+/// Exact product of two i128 values, as (sign, magnitude)
+fn signed_mul(x: i128, y: i128) -> (Ordering, Wide) {
+    (
+        (x.signum() * y.signum()).cmp(&0),
+        wide_mul(x.unsigned_abs(), y.unsigned_abs()),
+    )
+}
+
+/// Difference of two signed magnitudes, as (sign, magnitude)
+fn signed_sub((sx, x): (Ordering, Wide), (sy, y): (Ordering, Wide)) -> (Ordering, Wide) {
+    match (sx, sy) {
+        (_, Ordering::Equal) => (sx, x),
+        (Ordering::Equal, _) => (sy.reverse(), y),
+        _ if sx != sy => (sx, wide_add(x, y)),
+        _ => match x.cmp(&y) {
+            Ordering::Greater => (sx, wide_sub(x, y)),
+            Ordering::Less => (sx.reverse(), wide_sub(y, x)),
+            Ordering::Equal => (Ordering::Equal, wide(0)),
+        },
+    }
+}
+
+/// Signed magnitude as an i128, if it fits
+fn narrow((sign, (high, low)): (Ordering, Wide)) -> Option<i128> {
+    if high != 0 {
+        return None;
+    }
+    match sign {
+        Ordering::Less => 0_i128.checked_sub_unsigned(low),
+        _ => i128::try_from(low).ok(),
+    }
+}
+
+/// Compare the positive ratio p/q with φ exactly.
+///
+/// Walks the continued fraction of p/q against φ = [1; 1, 1, ...]. Only
+/// whether each term is 0, 1 or more matters, so subtraction suffices.
+fn cmp_ratio_with_phi(mut p: Wide, mut q: Wide) -> Ordering {
+    let mut flipped = false;
+
+    loop {
+        let ordering = if p < q {
+            // Term 0
+            Ordering::Less
+        } else {
+            let rem = wide_sub(p, q);
+            if rem >= q {
+                // Term of 2 or more
+                Ordering::Greater
+            } else if rem == wide(0) {
+                // p/q = 1 exactly, while φ continues with 1 + 1/φ
+                Ordering::Less
+            } else {
+                p = q;
+                q = rem;
+                flipped = !flipped;
+                continue;
+            }
+        };
+
+        return if flipped {
+            ordering.reverse()
+        } else {
+            ordering
+        };
+    }
+}
+
+/// Exact sign of a + bφ.
+fn sign_of(a: i128, b: i128) -> Ordering {
+    sign_of_parts(
+        (a.cmp(&0), wide(a.unsigned_abs())),
+        (b.cmp(&0), wide(b.unsigned_abs())),
+    )
+}
+
+/// Exact sign of a + bφ, with a and b given as (sign, magnitude).
+///
+/// 256-bit magnitudes let differences of cross-products of any two i128
+/// values be compared.
+fn sign_of_parts((sa, a): (Ordering, Wide), (sb, b): (Ordering, Wide)) -> Ordering {
+    match (sa, sb) {
+        (Ordering::Equal, sign) | (sign, Ordering::Equal) => sign,
+        (sa, sb) if sa == sb => sa,
+        // a < 0 < b: positive iff |a|/b < φ
+        (Ordering::Less, _) => cmp_ratio_with_phi(a, b).reverse(),
+        // b < 0 < a: positive iff a/|b| > φ
+        _ => cmp_ratio_with_phi(a, b),
+    }
+}
+
+/// Convert a + bφ to f64, avoiding cancellation when a and b have opposite signs.
+fn to_f64_parts(a: i128, b: i128) -> f64 {
+    let direct = a as f64 + b as f64 * PHI;
+    if (a < 0) == (b < 0) {
+        return direct;
+    }
+
+    // x = N(x) / x̄, where the conjugate x̄ = (a + b) - bφ has no cancellation
+    let norm = a
+        .checked_mul(a + b)
+        .and_then(|ab| b.checked_mul(b).and_then(|bb| ab.checked_sub(bb)));
+    match norm {
+        Some(norm) => norm as f64 / ((a + b) as f64 - b as f64 * PHI),
+        None => direct,
+    }
+}
+
+/// An element a + bφ of the ring `Z[φ]` with integer coefficients
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ZPhi {
+    /// Rational part
+    pub a: i128,
+    /// Coefficient of φ
+    pub b: i128,
+}
+
+impl ZPhi {
+    /// Zero
+    pub const ZERO: Self = Self::new(0, 0);
+
+    /// One
+    pub const ONE: Self = Self::new(1, 0);
+
+    /// The golden ratio φ
+    pub const PHI: Self = Self::new(0, 1);
+
+    /// Create a new element a + bφ
+    pub const fn new(a: i128, b: i128) -> Self {
+        Self { a, b }
+    }
+
+    /// Exact φ^n = F(n-1) + F(n)φ.
+    ///
+    /// Negative powers use φ^(-n) = (-1)^n (F(n+1) - F(n)φ).
+    ///
+    /// # Returns
+    /// φ^n, or None if a coefficient exceeds i128
+    pub fn phi_pow(n: i32) -> Option<Self> {
+        let k = n.unsigned_abs();
+        let f_k = i128::try_from(fibonacci(k).ok()?).ok()?;
+
+        if n >= 0 {
+            if k == 0 {
+                return Some(Self::ONE);
+            }
+            let f_prev = i128::try_from(fibonacci(k - 1).ok()?).ok()?;
+            Some(Self::new(f_prev, f_k))
+        } else {
+            let f_next = i128::try_from(fibonacci(k + 1).ok()?).ok()?;
+            let value = Self::new(f_next, -f_k);
+            Some(if k.is_multiple_of(2) { value } else { -value })
+        }
+    }
+
+    /// Galois conjugate a + bφ̄, where φ̄ = 1 - φ
+    ///
+    /// # Panics
+    /// Panics on i128 overflow in debug builds (wraps in release); see `checked_conjugate`
+    pub const fn conjugate(&self) -> Self {
+        Self::new(self.a + self.b, -self.b)
+    }
+
+    /// Checked Galois conjugate
+    pub fn checked_conjugate(&self) -> Option<Self> {
+        Some(Self::new(
+            self.a.checked_add(self.b)?,
+            self.b.checked_neg()?,
+        ))
+    }
+
+    /// Field norm N(x) = x × x̄ = a² + ab - b²
+    ///
+    /// # Panics
+    /// Panics on i128 overflow in debug builds (wraps in release); see `checked_norm`
+    pub const fn norm(&self) -> i128 {
+        self.a * self.a + self.a * self.b - self.b * self.b
+    }
+
+    /// Checked field norm, exact whenever the result fits in i128
+    pub fn checked_norm(&self) -> Option<i128> {
+        let (sign, ab) = signed_mul(self.a, self.b);
+        narrow(signed_sub(
+            signed_sub(signed_mul(self.a, self.a), signed_mul(self.b, self.b)),
+            (sign.reverse(), ab),
+        ))
+    }
+
+    /// Check if this element is a unit (norm ±1)
+    pub fn is_unit(&self) -> bool {
+        matches!(self.checked_norm(), Some(1 | -1))
+    }
+
+    /// Multiplicative inverse within `Z[φ]`.
+    ///
+    /// # Returns
+    /// The inverse if this element is a unit, otherwise None
+    pub fn inverse(&self) -> Option<Self> {
+        match self.checked_norm()? {
+            1 => self.checked_conjugate(),
+            -1 => self.checked_conjugate()?.checked_neg(),
+            _ => None,
+        }
+    }
+
+    /// Checked addition
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.a.checked_add(rhs.a)?,
+            self.b.checked_add(rhs.b)?,
+        ))
+    }
+
+    /// Checked subtraction
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.a.checked_sub(rhs.a)?,
+            self.b.checked_sub(rhs.b)?,
+        ))
+    }
+
+    /// Checked negation
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self::new(self.a.checked_neg()?, self.b.checked_neg()?))
+    }
+
+    /// Checked multiplication using φ² = φ + 1
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let ac = self.a.checked_mul(rhs.a)?;
+        let bd = self.b.checked_mul(rhs.b)?;
+        let ad = self.a.checked_mul(rhs.b)?;
+        let bc = self.b.checked_mul(rhs.a)?;
+        Some(Self::new(
+            ac.checked_add(bd)?,
+            ad.checked_add(bc)?.checked_add(bd)?,
+        ))
+    }
+
+    /// Checked exponentiation by squaring
+    pub fn checked_pow(self, mut exp: u32) -> Option<Self> {
+        let mut base = self;
+        let mut result = Self::ONE;
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Exponentiation by squaring.
+    ///
+    /// # Panics
+    /// Panics if a coefficient overflows i128
+    pub fn pow(self, exp: u32) -> Self {
+        self.checked_pow(exp).expect("ZPhi power overflows i128")
+    }
+
+    /// Exact sign of this element
+    pub fn signum(&self) -> i32 {
+        match sign_of(self.a, self.b) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        }
+    }
+
+    /// Convert to the nearest f64
+    pub fn to_f64(&self) -> f64 {
+        to_f64_parts(self.a, self.b)
+    }
+}
+
+impl From<i128> for ZPhi {
+    fn from(a: i128) -> Self {
+        Self::new(a, 0)
+    }
+}
+
+impl fmt::Display for ZPhi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.b < 0 {
+            write!(f, "{} - {}φ", self.a, self.b.unsigned_abs())
+        } else {
+            write!(f, "{} + {}φ", self.a, self.b)
+        }
+    }
+}
+
+/// # Panics
+/// Panics on i128 overflow in debug builds (wraps in release); see `checked_add`
+impl Add for ZPhi {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.a + rhs.a, self.b + rhs.b)
+    }
+}
+
+/// # Panics
+/// Panics on i128 overflow in debug builds (wraps in release); see `checked_sub`
+impl Sub for ZPhi {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.a - rhs.a, self.b - rhs.b)
+    }
+}
+
+/// # Panics
+/// Panics on i128 overflow in debug builds (wraps in release); see `checked_neg`
+impl Neg for ZPhi {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.a, -self.b)
+    }
+}
+
+/// # Panics
+/// Panics on i128 overflow in debug builds (wraps in release); see `checked_mul`
+impl Mul for ZPhi {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let bd = self.b * rhs.b;
+        Self::new(self.a * rhs.a + bd, self.a * rhs.b + self.b * rhs.a + bd)
+    }
+}
+
+/// Exact and total: differences are taken as unsigned magnitudes, so no
+/// pair of values can overflow
+impl Ord for ZPhi {
+    fn cmp(&self, other: &Self) -> Ordering {
+        sign_of_parts(
+            (self.a.cmp(&other.a), wide(self.a.abs_diff(other.a))),
+            (self.b.cmp(&other.b), wide(self.b.abs_diff(other.b))),
+        )
+    }
+}
+
+impl PartialOrd for ZPhi {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// An element (a + bφ) / d of the field `Q(φ)` with rational coefficients.
+///
+/// Always kept in lowest terms with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QPhi {
+    a: i128,
+    b: i128,
+    d: i128,
+}
+
+impl QPhi {
+    /// Zero
+    pub const ZERO: Self = Self { a: 0, b: 0, d: 1 };
+
+    /// One
+    pub const ONE: Self = Self { a: 1, b: 0, d: 1 };
+
+    /// The golden ratio φ
+    pub const PHI: Self = Self { a: 0, b: 1, d: 1 };
+
+    /// Create a new element (a + bφ) / d
+    ///
+    /// # Panics
+    /// Panics if d == 0, or if a coefficient in lowest terms overflows i128
+    /// (only possible with i128::MIN); see `checked_new`
+    pub fn new(a: i128, b: i128, d: i128) -> Self {
+        if d == 0 {
+            panic!("Denominator must be non-zero");
+        }
+        Self::checked_new(a, b, d).expect("QPhi coefficient overflows i128")
+    }
+
+    /// Create a new element (a + bφ) / d, reduced to lowest terms.
+    ///
+    /// # Returns
+    /// The element, or None if d == 0 or a reduced coefficient overflows i128
+    pub fn checked_new(a: i128, b: i128, d: i128) -> Option<Self> {
+        if d == 0 {
+            return None;
+        }
+
+        let g = gcd(gcd(a.unsigned_abs(), b.unsigned_abs()), d.unsigned_abs());
+        // Divide the magnitudes first so that i128::MIN never needs negating
+        let scale = |x: i128| {
+            let magnitude = x.unsigned_abs() / g;
+            if (x < 0) != (d < 0) {
+                0_i128.checked_sub_unsigned(magnitude)
+            } else {
+                i128::try_from(magnitude).ok()
+            }
+        };
+        Some(Self {
+            a: scale(a)?,
+            b: scale(b)?,
+            d: scale(d)?,
+        })
+    }
+
+    /// Rational part as (numerator, denominator)
+    pub fn rational_part(&self) -> (i128, i128) {
+        let g = gcd(self.a.unsigned_abs(), self.d.unsigned_abs()) as i128;
+        (self.a / g, self.d / g)
+    }
+
+    /// Coefficient of φ as (numerator, denominator)
+    pub fn phi_part(&self) -> (i128, i128) {
+        let g = gcd(self.b.unsigned_abs(), self.d.unsigned_abs()) as i128;
+        (self.b / g, self.d / g)
+    }
+
+    /// Common denominator
+    pub const fn denominator(&self) -> i128 {
+        self.d
+    }
+
+    /// Numerator a + bφ over the common denominator
+    pub const fn numerator(&self) -> ZPhi {
+        ZPhi::new(self.a, self.b)
+    }
+
+    /// Galois conjugate, replacing φ with 1 - φ
+    ///
+    /// # Panics
+    /// Panics if a coefficient overflows i128; see `checked_conjugate`
+    pub fn conjugate(&self) -> Self {
+        self.checked_conjugate()
+            .expect("QPhi coefficient overflows i128")
+    }
+
+    /// Checked Galois conjugate
+    pub fn checked_conjugate(&self) -> Option<Self> {
+        let conj = self.numerator().checked_conjugate()?;
+        Self::checked_new(conj.a, conj.b, self.d)
+    }
+
+    /// Multiplicative inverse x̄ / N(x).
+    ///
+    /// # Returns
+    /// The inverse, or None for zero or if a coefficient overflows i128
+    pub fn inverse(&self) -> Option<Self> {
+        let norm = self.numerator().checked_norm()?;
+        if norm == 0 {
+            return None;
+        }
+
+        let conj = self.numerator().checked_conjugate()?;
+        Self::checked_new(
+            self.d.checked_mul(conj.a)?,
+            self.d.checked_mul(conj.b)?,
+            norm,
+        )
+    }
+
+    /// Checked addition
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let cross = |x: i128, y: i128| x.checked_mul(rhs.d)?.checked_add(y.checked_mul(self.d)?);
+        Self::checked_new(
+            cross(self.a, rhs.a)?,
+            cross(self.b, rhs.b)?,
+            self.d.checked_mul(rhs.d)?,
+        )
+    }
+
+    /// Checked subtraction
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let cross = |x: i128, y: i128| x.checked_mul(rhs.d)?.checked_sub(y.checked_mul(self.d)?);
+        Self::checked_new(
+            cross(self.a, rhs.a)?,
+            cross(self.b, rhs.b)?,
+            self.d.checked_mul(rhs.d)?,
+        )
+    }
+
+    /// Checked negation
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            a: self.a.checked_neg()?,
+            b: self.b.checked_neg()?,
+            d: self.d,
+        })
+    }
+
+    /// Checked multiplication
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let num = self.numerator().checked_mul(rhs.numerator())?;
+        Self::checked_new(num.a, num.b, self.d.checked_mul(rhs.d)?)
+    }
+
+    /// Checked division
+    ///
+    /// # Returns
+    /// The quotient, or None for division by zero or on overflow
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.inverse()?)
+    }
+
+    /// Checked integer exponentiation, using the inverse for negative powers.
+    ///
+    /// # Returns
+    /// The power, or None for zero to a negative power or on overflow
+    pub fn checked_pow(self, exp: i32) -> Option<Self> {
+        let mut base = if exp < 0 { self.inverse()? } else { self };
+        let mut exp = exp.unsigned_abs();
+        let mut result = Self::ONE;
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Integer exponentiation, using the inverse for negative powers.
+    ///
+    /// # Panics
+    /// Panics if raising zero to a negative power, or if a coefficient
+    /// overflows i128
+    pub fn pow(self, exp: i32) -> Self {
+        if exp < 0 && self == Self::ZERO {
+            panic!("Cannot raise zero to a negative power");
+        }
+        self.checked_pow(exp)
+            .expect("QPhi coefficient overflows i128")
+    }
+
+    /// Exact sign of this element
+    pub fn signum(&self) -> i32 {
+        self.numerator().signum()
+    }
+
+    /// Convert to the nearest f64
+    pub fn to_f64(&self) -> f64 {
+        to_f64_parts(self.a, self.b) / self.d as f64
+    }
+}
+
+impl Default for QPhi {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<ZPhi> for QPhi {
+    fn from(value: ZPhi) -> Self {
+        Self::new(value.a, value.b, 1)
+    }
+}
+
+impl From<i128> for QPhi {
+    fn from(a: i128) -> Self {
+        Self::new(a, 0, 1)
+    }
+}
+
+impl fmt::Display for QPhi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.d == 1 {
+            write!(f, "{}", self.numerator())
+        } else {
+            write!(f, "({}) / {}", self.numerator(), self.d)
+        }
+    }
+}
+
+/// # Panics
+/// Panics if a coefficient overflows i128; see `checked_add`
+impl Add for QPhi {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs)
+            .expect("QPhi coefficient overflows i128")
+    }
+}
+
+/// # Panics
+/// Panics if a coefficient overflows i128; see `checked_sub`
+impl Sub for QPhi {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs)
+            .expect("QPhi coefficient overflows i128")
+    }
+}
+
+/// # Panics
+/// Panics if a coefficient is i128::MIN; see `checked_neg`
+impl Neg for QPhi {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.checked_neg().expect("QPhi coefficient overflows i128")
+    }
+}
+
+/// # Panics
+/// Panics if a coefficient overflows i128; see `checked_mul`
+impl Mul for QPhi {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs)
+            .expect("QPhi coefficient overflows i128")
+    }
+}
+
+/// # Panics
+/// Panics on division by zero, or if a coefficient overflows i128; see
+/// `checked_div`
+impl Div for QPhi {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        if rhs == Self::ZERO {
+            panic!("Division by zero");
+        }
+        self.checked_div(rhs)
+            .expect("QPhi coefficient overflows i128")
+    }
+}
+
+/// Exact and total: cross-products are formed in 256 bits, so no pair of
+/// values can overflow
+impl Ord for QPhi {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Denominators are positive, so cross-multiplying preserves order
+        let cross = |x: i128, y: i128| signed_sub(signed_mul(x, other.d), signed_mul(y, self.d));
+        sign_of_parts(cross(self.a, other.a), cross(self.b, other.b))
+    }
+}
+
+impl PartialOrd for QPhi {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phi::{phi_power, PHI_INVERSE, PHI_SQUARED};
+
+    #[test]
+    fn test_phi_identities_exact() {
+        assert_eq!(ZPhi::PHI * ZPhi::PHI, ZPhi::PHI + ZPhi::ONE);
+        assert_eq!(ZPhi::PHI.inverse(), Some(ZPhi::PHI - ZPhi::ONE));
+        assert_eq!(ZPhi::PHI.pow(2).to_f64(), PHI_SQUARED);
+        assert_eq!(ZPhi::new(2, 0).inverse(), None);
+    }
+
+    #[test]
+    fn test_phi_pow() {
+        for n in -40..=40 {
+            let exact = ZPhi::phi_pow(n).unwrap();
+            if n >= 0 {
+                assert_eq!(exact, ZPhi::PHI.pow(n as u32));
+            }
+            let relative = (exact.to_f64() - phi_power(n)).abs() / phi_power(n);
+            assert!(relative < 1e-12);
+        }
+        assert_eq!(ZPhi::phi_pow(-1), Some(ZPhi::new(-1, 1)));
+        assert_eq!(ZPhi::phi_pow(-2), Some(ZPhi::new(2, -1)));
+        assert!(ZPhi::phi_pow(200).is_none());
+        assert!(ZPhi::PHI.checked_pow(200).is_none());
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(ZPhi::PHI > ZPhi::ONE);
+        assert!(ZPhi::PHI < ZPhi::new(2, 0));
+        assert!(ZPhi::new(-8, 5) > ZPhi::ZERO);
+        assert!(ZPhi::new(-13, 8) < ZPhi::ZERO);
+        assert!(ZPhi::new(13, -8) > ZPhi::ZERO);
+        assert_eq!(ZPhi::phi_pow(-30).unwrap().signum(), 1);
+        assert!(QPhi::new(3, 0, 2) < QPhi::PHI);
+        assert!(QPhi::new(5, 0, 3) > QPhi::PHI);
+
+        // Differences beyond i128 still compare exactly
+        assert!(ZPhi::new(i128::MAX, 0) > ZPhi::new(-1, 0));
+        assert!(ZPhi::new(i128::MIN, i128::MAX) > ZPhi::new(i128::MAX, i128::MIN));
+        assert!(ZPhi::new(i128::MIN, 0) < ZPhi::new(i128::MAX, -1));
+        assert!(QPhi::new(i128::MAX, 0, 3) > QPhi::new(1, 0, i128::MAX));
+        assert_eq!(ZPhi::new(i128::MAX, 0).checked_sub(ZPhi::new(-1, 0)), None);
+        assert_eq!(ZPhi::new(i128::MIN, 0).checked_neg(), None);
+
+        // Cross-products beyond i128 still compare exactly, agreeing with Eq
+        let x = QPhi::new(i128::MAX, 0, i128::MAX - 1);
+        let y = QPhi::new(i128::MAX - 2, 0, i128::MAX - 3);
+        assert_ne!(x, y);
+        assert_eq!(x.cmp(&y), Ordering::Less);
+        assert_eq!(y.cmp(&x), Ordering::Greater);
+        assert_eq!(x.cmp(&x), Ordering::Equal);
+        let big = QPhi::new(i128::MIN, i128::MAX, i128::MAX);
+        assert!(big < QPhi::new(i128::MIN, i128::MAX, i128::MAX - 1));
+        assert_eq!(wide_mul(u128::MAX, u128::MAX), (u128::MAX - 1, 1));
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        assert_eq!(ZPhi::new(i128::MAX / 2, 1).inverse(), None);
+        assert_eq!(ZPhi::new(i128::MAX / 2, 1).checked_norm(), None);
+        assert!(!ZPhi::new(i128::MAX, i128::MAX).is_unit());
+        assert_eq!(ZPhi::new(i128::MAX, 1).checked_conjugate(), None);
+
+        // Large units still invert, although a² alone overflows i128
+        let unit = ZPhi::phi_pow(180).unwrap();
+        assert_eq!(unit.checked_norm(), Some(1));
+        assert_eq!(unit.inverse(), ZPhi::phi_pow(-180));
+
+        assert_eq!(QPhi::checked_new(i128::MIN, 0, -1), None);
+        assert_eq!(QPhi::checked_new(1, 0, 0), None);
+        assert_eq!(
+            QPhi::new(i128::MIN, 0, 2).numerator(),
+            ZPhi::new(i128::MIN / 2, 0)
+        );
+        let max = QPhi::from(i128::MAX);
+        assert_eq!(max.checked_add(QPhi::ONE), None);
+        assert_eq!(max.checked_sub(-QPhi::ONE), None);
+        assert_eq!(max.checked_mul(QPhi::from(2)), None);
+        assert_eq!(QPhi::new(i128::MIN, 1, 1).checked_neg(), None);
+        assert_eq!(QPhi::ONE.checked_div(QPhi::ZERO), None);
+        assert_eq!(QPhi::ZERO.checked_pow(-1), None);
+        assert_eq!(QPhi::PHI.checked_pow(200), None);
+        assert_eq!(
+            QPhi::new(1, 2, 3).checked_sub(QPhi::new(1, 2, 3)),
+            Some(QPhi::ZERO)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "QPhi coefficient overflows i128")]
+    fn test_qphi_new_overflow() {
+        QPhi::new(i128::MIN, 0, -1);
+    }
+
+    #[test]
+    fn test_qphi_field() {
+        let x = QPhi::new(3, 2, 5);
+        assert_eq!(x * x.inverse().unwrap(), QPhi::ONE);
+        assert_eq!(QPhi::PHI.pow(-1), QPhi::PHI - QPhi::ONE);
+        assert_eq!(QPhi::new(2, 4, -6), QPhi::new(-1, -2, 3));
+        assert_eq!(QPhi::ONE / QPhi::PHI, QPhi::from(ZPhi::new(-1, 1)));
+        assert!((QPhi::PHI.pow(-1).to_f64() - PHI_INVERSE).abs() < 1e-15);
+        assert_eq!(QPhi::new(1, 1, 2).to_string(), "(1 + 1φ) / 2");
+    }
+}